@@ -0,0 +1,29 @@
+use std::fmt;
+
+use crate::evaluator::object::Object;
+
+/// A typed evaluation failure, mirroring `ParseError`'s shape one layer
+/// down the pipeline. `Return` isn't really a failure — it's the early-exit
+/// signal a `return` statement raises through block evaluation, unwrapped
+/// back into a plain `Ok(Object)` at the function-call boundary and at the
+/// top level of [`crate::evaluator::eval`].
+#[derive(Clone, Debug)]
+pub enum EvalError {
+    TypeError(String),
+    UndefinedVariable(String),
+    NotCallable(String),
+    UnhashableType(String),
+    Return(Object),
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::TypeError(msg) => write!(f, "{}", msg),
+            EvalError::UndefinedVariable(name) => write!(f, "identifier not found: {}", name),
+            EvalError::NotCallable(type_name) => write!(f, "not a function: {}", type_name),
+            EvalError::UnhashableType(type_name) => write!(f, "unusable as hash key: {}", type_name),
+            EvalError::Return(_) => write!(f, "return outside of function"),
+        }
+    }
+}
@@ -0,0 +1,452 @@
+//! A tree-walking evaluator over the parsed AST, run after `Parser::parse`
+//! (and optionally `Program::optimize`) hand back a `Program`.
+
+pub mod environment;
+pub mod error;
+pub mod object;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::evaluator::environment::Environment;
+use crate::evaluator::error::EvalError;
+use crate::evaluator::object::{FunctionObject, Object};
+use crate::parser::expression::{CallExpression, Expression, HashLiteral, IfExpression};
+use crate::parser::program::Program;
+use crate::parser::statement::{BlockStatement, Statement};
+use crate::token::Token;
+
+/// Evaluates a whole program. A top-level `return` ends evaluation early
+/// with its value rather than propagating as an error, unlike
+/// `eval_block_statement` where it must keep propagating so nested `if`
+/// blocks inside a function body can short-circuit correctly.
+pub fn eval(program: &Program, env: &Rc<RefCell<Environment>>) -> Result<Object, EvalError> {
+    let mut result = Object::Null;
+    for statement in &program.statements {
+        match eval_statement(statement, env) {
+            Ok(value) => result = value,
+            Err(EvalError::Return(value)) => return Ok(value),
+            Err(other) => return Err(other),
+        }
+    }
+    Ok(result)
+}
+
+fn eval_block_statement(block: &BlockStatement, env: &Rc<RefCell<Environment>>) -> Result<Object, EvalError> {
+    let mut result = Object::Null;
+    for statement in &block.statements {
+        result = eval_statement(statement, env)?;
+    }
+    Ok(result)
+}
+
+fn eval_statement(statement: &Statement, env: &Rc<RefCell<Environment>>) -> Result<Object, EvalError> {
+    match statement {
+        Statement::ExpressionStatement(s) => match &s.expression {
+            Some(expr) => eval_expression(expr, env),
+            None => Ok(Object::Null),
+        },
+        Statement::LetStatement(s) => {
+            let value = match &s.value {
+                Some(expr) => eval_expression(expr, env)?,
+                None => Object::Null,
+            };
+            env.borrow_mut().set(s.identifier.token.literal().to_string(), value);
+            Ok(Object::Null)
+        }
+        Statement::ReturnStatement(s) => {
+            let value = match &s.value {
+                Some(expr) => eval_expression(expr, env)?,
+                None => Object::Null,
+            };
+            Err(EvalError::Return(value))
+        }
+    }
+}
+
+fn eval_expression(expr: &Expression, env: &Rc<RefCell<Environment>>) -> Result<Object, EvalError> {
+    match expr {
+        Expression::IntegerLiteral(lit) => Ok(Object::Integer(lit.value)),
+        Expression::FloatLiteral(lit) => Ok(Object::Float(lit.value)),
+        Expression::BooleanLiteral(lit) => Ok(Object::Boolean(lit.value)),
+        Expression::StringLiteral(lit) => Ok(Object::String(lit.value.clone())),
+        Expression::CharLiteral(_) => Err(EvalError::TypeError("CHAR literals are not yet supported".to_string())),
+        Expression::ByteStringLiteral(_) => {
+            Err(EvalError::TypeError("BYTE_STRING literals are not yet supported".to_string()))
+        }
+        Expression::Identifier(ident) => env
+            .borrow()
+            .get(ident.token.literal())
+            .ok_or_else(|| EvalError::UndefinedVariable(ident.token.literal().to_string())),
+        Expression::PrefixExpression(p) => {
+            let right = eval_expression(&p.right, env)?;
+            eval_prefix_expression(&p.token, right)
+        }
+        Expression::InfixExpression(i) => {
+            let left = eval_expression(&i.left, env)?;
+            let right = eval_expression(&i.right, env)?;
+            eval_infix_expression(&i.token, left, right)
+        }
+        Expression::IfExpression(if_exp) => eval_if_expression(if_exp, env),
+        Expression::FunctionLiteral(fl) => Ok(Object::Function(FunctionObject {
+            parameters: fl.parameters.clone(),
+            body: fl.body.clone(),
+            env: Rc::clone(env),
+        })),
+        Expression::CallExpression(call) => eval_call_expression(call, env),
+        Expression::ArrayLiteral(a) => {
+            let elements = a
+                .elements
+                .iter()
+                .map(|e| eval_expression(e, env))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Object::Array(elements))
+        }
+        Expression::HashLiteral(h) => eval_hash_literal(h, env),
+    }
+}
+
+fn is_truthy(obj: &Object) -> bool {
+    !matches!(obj, Object::Boolean(false) | Object::Null)
+}
+
+fn eval_prefix_expression(token: &Token, right: Object) -> Result<Object, EvalError> {
+    match token {
+        Token::BANG => Ok(Object::Boolean(!is_truthy(&right))),
+        Token::MINUS => match right {
+            Object::Integer(value) => value
+                .checked_neg()
+                .map(Object::Integer)
+                .ok_or_else(|| EvalError::TypeError("integer overflow".to_string())),
+            Object::Float(value) => Ok(Object::Float(-value)),
+            other => Err(EvalError::TypeError(format!("unknown operator: -{}", other.type_name()))),
+        },
+        other => Err(EvalError::TypeError(format!(
+            "unknown operator: {}{}",
+            other.literal(),
+            right.type_name()
+        ))),
+    }
+}
+
+/// Dispatches on the operand types, not just the operator, since an
+/// `InfixExpression` can mix `IntegerLiteral` and `FloatLiteral` operands
+/// (`1 + 2.5`) — promoting the integer side to `f64` here is exactly the
+/// evaluator's job the parser and optimizer both defer.
+fn eval_infix_expression(token: &Token, left: Object, right: Object) -> Result<Object, EvalError> {
+    match (left, right) {
+        (Object::Integer(l), Object::Integer(r)) => eval_integer_infix(token, l, r),
+        (Object::Float(l), Object::Float(r)) => eval_float_infix(token, l, r),
+        (Object::Integer(l), Object::Float(r)) => eval_float_infix(token, l as f64, r),
+        (Object::Float(l), Object::Integer(r)) => eval_float_infix(token, l, r as f64),
+        (Object::String(l), Object::String(r)) => eval_string_infix(token, &l, &r),
+        (Object::Boolean(l), Object::Boolean(r)) => eval_boolean_infix(token, l, r),
+        (l, r) => Err(EvalError::TypeError(format!(
+            "type mismatch: {} {} {}",
+            l.type_name(),
+            token.literal(),
+            r.type_name()
+        ))),
+    }
+}
+
+fn eval_integer_infix(token: &Token, left: i64, right: i64) -> Result<Object, EvalError> {
+    match token {
+        Token::PLUS => left
+            .checked_add(right)
+            .map(Object::Integer)
+            .ok_or_else(|| EvalError::TypeError("integer overflow".to_string())),
+        Token::MINUS => left
+            .checked_sub(right)
+            .map(Object::Integer)
+            .ok_or_else(|| EvalError::TypeError("integer overflow".to_string())),
+        Token::ASTERISK => left
+            .checked_mul(right)
+            .map(Object::Integer)
+            .ok_or_else(|| EvalError::TypeError("integer overflow".to_string())),
+        Token::SLASH => {
+            if right == 0 {
+                Err(EvalError::TypeError("division by zero".to_string()))
+            } else {
+                Ok(Object::Integer(left / right))
+            }
+        }
+        Token::POW => u32::try_from(right)
+            .ok()
+            .and_then(|exp| left.checked_pow(exp))
+            .map(Object::Integer)
+            .ok_or_else(|| EvalError::TypeError("integer overflow".to_string())),
+        Token::LT => Ok(Object::Boolean(left < right)),
+        Token::GT => Ok(Object::Boolean(left > right)),
+        Token::EQ => Ok(Object::Boolean(left == right)),
+        Token::NEQ => Ok(Object::Boolean(left != right)),
+        other => Err(EvalError::TypeError(format!("unknown operator: INTEGER {} INTEGER", other.literal()))),
+    }
+}
+
+fn eval_float_infix(token: &Token, left: f64, right: f64) -> Result<Object, EvalError> {
+    match token {
+        Token::PLUS => Ok(Object::Float(left + right)),
+        Token::MINUS => Ok(Object::Float(left - right)),
+        Token::ASTERISK => Ok(Object::Float(left * right)),
+        Token::SLASH => Ok(Object::Float(left / right)),
+        Token::POW => Ok(Object::Float(left.powf(right))),
+        Token::LT => Ok(Object::Boolean(left < right)),
+        Token::GT => Ok(Object::Boolean(left > right)),
+        Token::EQ => Ok(Object::Boolean(left == right)),
+        Token::NEQ => Ok(Object::Boolean(left != right)),
+        other => Err(EvalError::TypeError(format!("unknown operator: FLOAT {} FLOAT", other.literal()))),
+    }
+}
+
+fn eval_string_infix(token: &Token, left: &str, right: &str) -> Result<Object, EvalError> {
+    match token {
+        Token::PLUS => Ok(Object::String(format!("{}{}", left, right))),
+        Token::EQ => Ok(Object::Boolean(left == right)),
+        Token::NEQ => Ok(Object::Boolean(left != right)),
+        other => Err(EvalError::TypeError(format!("unknown operator: STRING {} STRING", other.literal()))),
+    }
+}
+
+fn eval_boolean_infix(token: &Token, left: bool, right: bool) -> Result<Object, EvalError> {
+    match token {
+        Token::EQ => Ok(Object::Boolean(left == right)),
+        Token::NEQ => Ok(Object::Boolean(left != right)),
+        other => Err(EvalError::TypeError(format!("unknown operator: BOOLEAN {} BOOLEAN", other.literal()))),
+    }
+}
+
+fn eval_if_expression(if_exp: &IfExpression, env: &Rc<RefCell<Environment>>) -> Result<Object, EvalError> {
+    let condition = eval_expression(&if_exp.condition, env)?;
+    if is_truthy(&condition) {
+        eval_block_statement(&if_exp.consequence, env)
+    } else if let Some(alternative) = &if_exp.alternative {
+        eval_block_statement(alternative, env)
+    } else {
+        Ok(Object::Null)
+    }
+}
+
+fn eval_call_expression(call: &CallExpression, env: &Rc<RefCell<Environment>>) -> Result<Object, EvalError> {
+    let function = eval_expression(&call.function, env)?;
+    let args = call
+        .args
+        .iter()
+        .map(|arg| eval_expression(arg, env))
+        .collect::<Result<Vec<_>, _>>()?;
+    apply_function(function, args)
+}
+
+/// Binds `args` into a fresh environment parented to the function's closure
+/// environment (not the caller's), then evaluates the body. A `Return`
+/// raised inside is unwrapped here rather than propagated further, so it
+/// can't leak out and short-circuit the caller's own enclosing blocks.
+fn apply_function(function: Object, args: Vec<Object>) -> Result<Object, EvalError> {
+    let Object::Function(func) = function else {
+        return Err(EvalError::NotCallable(function.type_name().to_string()));
+    };
+
+    let call_env = Environment::new_enclosed(Rc::clone(&func.env));
+    for (param, arg) in func.parameters.iter().zip(args) {
+        call_env.borrow_mut().set(param.token.literal().to_string(), arg);
+    }
+
+    match eval_block_statement(&func.body, &call_env) {
+        Ok(value) => Ok(value),
+        Err(EvalError::Return(value)) => Ok(value),
+        Err(other) => Err(other),
+    }
+}
+
+fn eval_hash_literal(hash: &HashLiteral, env: &Rc<RefCell<Environment>>) -> Result<Object, EvalError> {
+    let mut pairs = HashMap::new();
+    for (key_expr, value_expr) in &hash.paris {
+        let key = eval_expression(key_expr, env)?;
+        let value = eval_expression(value_expr, env)?;
+        pairs.insert(key.hash_key()?, value);
+    }
+    Ok(Object::Hash(pairs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::object::HashKey;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+
+    fn test_eval(input: &str) -> Object {
+        let program = Parser::new(Lexer::new(input))
+            .parse()
+            .unwrap_or_else(|errors| panic!("parser errors: {:?}", errors));
+        eval(&program, &Environment::new()).unwrap_or_else(|err| panic!("eval error: {}", err))
+    }
+
+    fn test_eval_err(input: &str) -> EvalError {
+        let program = Parser::new(Lexer::new(input))
+            .parse()
+            .unwrap_or_else(|errors| panic!("parser errors: {:?}", errors));
+        match eval(&program, &Environment::new()) {
+            Ok(value) => panic!("expected eval error, got {:?}", value),
+            Err(err) => err,
+        }
+    }
+
+    #[test]
+    fn evaluates_integer_expressions() {
+        let cases = vec![
+            ("5", 5),
+            ("10", 10),
+            ("-5", -5),
+            ("5 + 5 * 2", 15),
+            ("(5 + 10) / 3", 5),
+            ("2 ** 3 ** 2", 512),
+        ];
+        for (input, expected) in cases {
+            match test_eval(input) {
+                Object::Integer(value) => assert_eq!(value, expected, "input: {}", input),
+                other => panic!("expected Integer for {:?}, got {:?}", input, other),
+            }
+        }
+    }
+
+    #[test]
+    fn evaluates_float_expressions() {
+        match test_eval("1 + 2.5") {
+            Object::Float(value) => assert_eq!(value, 3.5),
+            other => panic!("expected Float, got {:?}", other),
+        }
+        match test_eval("2.0 ** 3.0") {
+            Object::Float(value) => assert_eq!(value, 8.0),
+            other => panic!("expected Float, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn evaluates_boolean_expressions() {
+        let cases = vec![("true", true), ("1 < 2", true), ("1 > 2", false), ("1 == 1", true), ("!true", false)];
+        for (input, expected) in cases {
+            match test_eval(input) {
+                Object::Boolean(value) => assert_eq!(value, expected, "input: {}", input),
+                other => panic!("expected Boolean for {:?}, got {:?}", input, other),
+            }
+        }
+    }
+
+    #[test]
+    fn evaluates_if_else_expressions() {
+        match test_eval("if (true) { 10 } else { 20 }") {
+            Object::Integer(value) => assert_eq!(value, 10),
+            other => panic!("expected Integer, got {:?}", other),
+        }
+        match test_eval("if (false) { 10 }") {
+            Object::Null => {}
+            other => panic!("expected Null, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn return_in_nested_if_unwraps_at_program_boundary() {
+        let input = "
+if (10 > 1) {
+    if (10 > 1) {
+        return 10;
+    }
+    return 1;
+}
+";
+        match test_eval(input) {
+            Object::Integer(value) => assert_eq!(value, 10),
+            other => panic!("expected Integer, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn let_statements_bind_identifiers() {
+        match test_eval("let a = 5 * 5; a;") {
+            Object::Integer(value) => assert_eq!(value, 25),
+            other => panic!("expected Integer, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn functions_apply_with_closure_environment() {
+        let input = "
+let newAdder = fn(x) {
+    fn(y) { x + y };
+};
+let addTwo = newAdder(2);
+addTwo(3);
+";
+        match test_eval(input) {
+            Object::Integer(value) => assert_eq!(value, 5),
+            other => panic!("expected Integer, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn string_literals_concatenate() {
+        match test_eval(r#""Hello" + " " + "World!""#) {
+            Object::String(value) => assert_eq!(value, "Hello World!"),
+            other => panic!("expected String, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn array_literals_evaluate_elements() {
+        match test_eval("[1, 2 * 2, 3 + 3]") {
+            Object::Array(elements) => {
+                assert_eq!(elements.len(), 3);
+                match &elements[1] {
+                    Object::Integer(value) => assert_eq!(*value, 4),
+                    other => panic!("expected Integer, got {:?}", other),
+                }
+            }
+            other => panic!("expected Array, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn hash_literals_key_by_evaluated_value() {
+        match test_eval(r#"{"one": 1, "two": 2}"#) {
+            Object::Hash(pairs) => match pairs.get(&HashKey::String("one".to_string())) {
+                Some(Object::Integer(value)) => assert_eq!(*value, 1),
+                other => panic!("expected Some(Integer(1)), got {:?}", other),
+            },
+            other => panic!("expected Hash, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn undefined_variable_is_an_error() {
+        match test_eval_err("foobar;") {
+            EvalError::UndefinedVariable(name) => assert_eq!(name, "foobar"),
+            other => panic!("expected UndefinedVariable, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn type_mismatch_is_an_error() {
+        match test_eval_err("5 + true;") {
+            EvalError::TypeError(_) => {}
+            other => panic!("expected TypeError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn calling_a_non_function_is_an_error() {
+        match test_eval_err("let x = 5; x(1);") {
+            EvalError::NotCallable(type_name) => assert_eq!(type_name, "INTEGER"),
+            other => panic!("expected NotCallable, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn non_hashable_key_is_an_error() {
+        match test_eval_err("{[1]: 1}") {
+            EvalError::UnhashableType(type_name) => assert_eq!(type_name, "ARRAY"),
+            other => panic!("expected UnhashableType, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::evaluator::environment::Environment;
+use crate::evaluator::error::EvalError;
+use crate::parser::expression::Identifier;
+use crate::parser::statement::BlockStatement;
+
+#[derive(Clone, Debug)]
+pub enum Object {
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    String(String),
+    Array(Vec<Object>),
+    Hash(HashMap<HashKey, Object>),
+    Function(FunctionObject),
+    Null,
+}
+
+impl Object {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Object::Integer(_) => "INTEGER",
+            Object::Float(_) => "FLOAT",
+            Object::Boolean(_) => "BOOLEAN",
+            Object::String(_) => "STRING",
+            Object::Array(_) => "ARRAY",
+            Object::Hash(_) => "HASH",
+            Object::Function(_) => "FUNCTION",
+            Object::Null => "NULL",
+        }
+    }
+
+    /// Returns the key this object hashes to when used as a key in a hash
+    /// literal. Only integers, booleans, and strings are
+    /// hashable — arrays, hashes, functions, and `null` are not, mirroring
+    /// the restriction in the host language itself.
+    pub fn hash_key(&self) -> Result<HashKey, EvalError> {
+        match self {
+            Object::Integer(value) => Ok(HashKey::Integer(*value)),
+            Object::Boolean(value) => Ok(HashKey::Boolean(*value)),
+            Object::String(value) => Ok(HashKey::String(value.clone())),
+            other => Err(EvalError::UnhashableType(other.type_name().to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Object::Integer(value) => write!(f, "{}", value),
+            Object::Float(value) => write!(f, "{}", value),
+            Object::Boolean(value) => write!(f, "{}", value),
+            Object::String(value) => write!(f, "{}", value),
+            Object::Array(elements) => {
+                let elements: Vec<String> = elements.iter().map(|e| e.to_string()).collect();
+                write!(f, "[{}]", elements.join(", "))
+            }
+            Object::Hash(pairs) => {
+                let pairs: Vec<String> = pairs.iter().map(|(k, v)| format!("{}: {}", k, v)).collect();
+                write!(f, "{{{}}}", pairs.join(", "))
+            }
+            Object::Function(func) => {
+                let params: Vec<String> = func.parameters.iter().map(|p| p.to_string()).collect();
+                write!(f, "fn({}) {{\n{}\n}}", params.join(", "), func.body)
+            }
+            Object::Null => write!(f, "null"),
+        }
+    }
+}
+
+/// A runtime hash key, restricted to the subset of `Object` variants that
+/// are actually hashable. Keeping this separate from `Object` avoids
+/// needing `Hash`/`Eq` impls for `Array`/`Hash`/`Function`/`Null`, which
+/// either can't be compared meaningfully or would require an `Eq` tradeoff
+/// like the one `FloatLiteral` makes in the AST.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HashKey {
+    Integer(i64),
+    Boolean(bool),
+    String(String),
+}
+
+impl fmt::Display for HashKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashKey::Integer(value) => write!(f, "{}", value),
+            HashKey::Boolean(value) => write!(f, "{}", value),
+            HashKey::String(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// A closure: its parameter list and body from the `FunctionLiteral` it was
+/// evaluated from, plus the environment it was defined in — looking up a
+/// free variable in the function's body walks this chain instead of just
+/// the call's fresh argument scope.
+#[derive(Clone, Debug)]
+pub struct FunctionObject {
+    pub parameters: Vec<Identifier>,
+    pub body: BlockStatement,
+    pub env: Rc<RefCell<Environment>>,
+}
@@ -0,0 +1,567 @@
+use crate::token::Token;
+
+/// A 1-based line/column location in the source, captured at the start of a token.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}:{}", self.line, self.column)
+    }
+}
+
+/// A lexical failure encountered while decoding a string escape. Surfaced to
+/// the parser as a `Token::ILLEGAL` carrying this message, rather than
+/// widening `Lexer::next_token`'s signature to a `Result` — the existing
+/// `NoPrefixParseFn` error path already reports illegal tokens with position.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LexerError {
+    MalformedEscapeSequence { found: char },
+    InvalidUnicodeScalar { codepoint: u32 },
+    MalformedNumberLiteral { literal: String },
+    MalformedCharLiteral,
+}
+
+impl std::fmt::Display for LexerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LexerError::MalformedEscapeSequence { found } => {
+                write!(f, "malformed escape sequence '\\{}'", found)
+            }
+            LexerError::InvalidUnicodeScalar { codepoint } => {
+                write!(f, "'\\u{{{:x}}}' is not a valid Unicode scalar value", codepoint)
+            }
+            LexerError::MalformedNumberLiteral { literal } => {
+                write!(f, "malformed number literal '{}'", literal)
+            }
+            LexerError::MalformedCharLiteral => {
+                write!(f, "malformed char literal: expected exactly one scalar value between ''")
+            }
+        }
+    }
+}
+
+/// What ended a string segment: a closing `"`, the start of `${` (a new
+/// interpolation), or unterminated input.
+enum StringSegmentEnd {
+    Closed,
+    Interpolation,
+    Eof,
+}
+
+pub struct Lexer {
+    input: Vec<char>,
+    position: usize,
+    read_position: usize,
+    ch: char,
+    line: usize,
+    column: usize,
+    /// One entry per currently-open `${ ... }` interpolation, holding the
+    /// nesting depth of unrelated `{`/`}` pairs inside it (e.g. a hash
+    /// literal) so the interpolation's own closing `}` isn't mistaken for
+    /// one of those.
+    interp_brace_depth: Vec<usize>,
+}
+
+impl Lexer {
+    pub fn new(input: &str) -> Self {
+        let mut lexer = Lexer {
+            input: input.chars().collect(),
+            position: 0,
+            read_position: 0,
+            ch: '\0',
+            line: 1,
+            column: 0,
+            interp_brace_depth: Vec::new(),
+        };
+        lexer.read_char();
+        lexer
+    }
+
+    fn read_char(&mut self) {
+        self.ch = if self.read_position >= self.input.len() {
+            '\0'
+        } else {
+            self.input[self.read_position]
+        };
+
+        if (self.position != 0 || self.read_position != 0)
+            && self.input.get(self.position) == Some(&'\n')
+        {
+            self.line += 1;
+            self.column = 0;
+        }
+        self.column += 1;
+
+        self.position = self.read_position;
+        self.read_position += 1;
+    }
+
+    fn peek_char(&self) -> char {
+        if self.read_position >= self.input.len() {
+            '\0'
+        } else {
+            self.input[self.read_position]
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.ch.is_whitespace() {
+            self.read_char();
+        }
+    }
+
+    fn read_identifier(&mut self) -> String {
+        let start = self.position;
+        while self.ch.is_alphabetic() || self.ch == '_' {
+            self.read_char();
+        }
+        self.input[start..self.position].iter().collect()
+    }
+
+    /// Reads an integer or floating-point literal, starting at a digit or
+    /// at a leading `.` (only when immediately followed by a digit, e.g.
+    /// `.5`). Returns the source text and whether a `.` or exponent marker
+    /// was seen. A trailing `.` like `5.` is accepted (value `5.0`); a
+    /// second `.` immediately after a complete number, like the `.3` in
+    /// `1.2.3`, is flagged as malformed rather than silently split into two
+    /// literals.
+    fn read_number(&mut self) -> Result<(String, bool), LexerError> {
+        let start = self.position;
+        let mut is_float = false;
+
+        if self.ch == '.' {
+            is_float = true;
+            self.read_char();
+            while self.ch.is_ascii_digit() {
+                self.read_char();
+            }
+        } else {
+            while self.ch.is_ascii_digit() {
+                self.read_char();
+            }
+
+            if self.ch == '.' {
+                is_float = true;
+                self.read_char();
+                while self.ch.is_ascii_digit() {
+                    self.read_char();
+                }
+            }
+        }
+
+        if self.ch == 'e' || self.ch == 'E' {
+            let mut lookahead = self.read_position;
+            if self.input.get(lookahead).is_some_and(|c| *c == '+' || *c == '-') {
+                lookahead += 1;
+            }
+            if self.input.get(lookahead).is_some_and(char::is_ascii_digit) {
+                is_float = true;
+                self.read_char();
+                if self.ch == '+' || self.ch == '-' {
+                    self.read_char();
+                }
+                while self.ch.is_ascii_digit() {
+                    self.read_char();
+                }
+            }
+        }
+
+        let literal: String = self.input[start..self.position].iter().collect();
+
+        if self.ch == '.' {
+            return Err(LexerError::MalformedNumberLiteral { literal });
+        }
+
+        Ok((literal, is_float))
+    }
+
+    /// Reads string text up to (and consuming) a closing `"`, up to (and
+    /// consuming) the `${` that starts an interpolation, or to EOF. Escape
+    /// sequences are decoded as they're read.
+    fn read_string_segment(&mut self) -> Result<(String, StringSegmentEnd), LexerError> {
+        let mut value = String::new();
+        loop {
+            self.read_char();
+            match self.ch {
+                '"' => {
+                    self.read_char();
+                    return Ok((value, StringSegmentEnd::Closed));
+                }
+                '\0' => return Ok((value, StringSegmentEnd::Eof)),
+                '$' if self.peek_char() == '{' => {
+                    self.read_char();
+                    self.read_char();
+                    return Ok((value, StringSegmentEnd::Interpolation));
+                }
+                '\\' => {
+                    self.read_char();
+                    value.push(self.read_escape()?);
+                }
+                c => value.push(c),
+            }
+        }
+    }
+
+    /// Decodes the escape sequence starting at `self.ch` (the character
+    /// right after the `\`), leaving `self.ch` on the last character it
+    /// consumed.
+    fn read_escape(&mut self) -> Result<char, LexerError> {
+        match self.ch {
+            'n' => Ok('\n'),
+            't' => Ok('\t'),
+            'r' => Ok('\r'),
+            '\\' => Ok('\\'),
+            '"' => Ok('"'),
+            '\'' => Ok('\''),
+            '0' => Ok('\0'),
+            'u' => self.read_unicode_escape(),
+            found => Err(LexerError::MalformedEscapeSequence { found }),
+        }
+    }
+
+    /// Decodes a `\u{XXXX}` escape with `self.ch == 'u'`, leaving `self.ch`
+    /// on the closing `}`.
+    fn read_unicode_escape(&mut self) -> Result<char, LexerError> {
+        if self.peek_char() != '{' {
+            return Err(LexerError::MalformedEscapeSequence { found: 'u' });
+        }
+        self.read_char();
+        self.read_char();
+
+        let mut hex = String::new();
+        while self.ch.is_ascii_hexdigit() {
+            hex.push(self.ch);
+            self.read_char();
+        }
+
+        if self.ch != '}' {
+            return Err(LexerError::MalformedEscapeSequence { found: self.ch });
+        }
+
+        let codepoint = u32::from_str_radix(&hex, 16)
+            .map_err(|_| LexerError::MalformedEscapeSequence { found: 'u' })?;
+        char::from_u32(codepoint).ok_or(LexerError::InvalidUnicodeScalar { codepoint })
+    }
+
+    /// Decodes a `'...'` char literal with `self.ch == '\''` at entry,
+    /// leaving `self.ch` on the closing `'`. Errors if the literal is empty,
+    /// unterminated, or holds more than one scalar value.
+    fn read_char_literal(&mut self) -> Result<char, LexerError> {
+        self.read_char();
+        let value = match self.ch {
+            '\\' => {
+                self.read_char();
+                self.read_escape()?
+            }
+            '\'' | '\0' => return Err(LexerError::MalformedCharLiteral),
+            c => c,
+        };
+
+        self.read_char();
+        if self.ch != '\'' {
+            return Err(LexerError::MalformedCharLiteral);
+        }
+        Ok(value)
+    }
+
+    /// Reads byte-string text (`b"..."`) up to (and consuming) the closing
+    /// `"`, decoding escapes the same way `read_string_segment` does for
+    /// regular strings. Byte strings don't support `${...}` interpolation,
+    /// so unlike `read_string_segment` this has no boundary-token case.
+    fn read_byte_string(&mut self) -> Result<String, LexerError> {
+        let mut value = String::new();
+        loop {
+            self.read_char();
+            match self.ch {
+                '"' => {
+                    self.read_char();
+                    return Ok(value);
+                }
+                '\0' => return Ok(value),
+                '\\' => {
+                    self.read_char();
+                    value.push(self.read_escape()?);
+                }
+                c => value.push(c),
+            }
+        }
+    }
+
+    /// Scans a string segment and wraps it as the appropriate token: a
+    /// plain `STRING` when it closes or hits EOF, or an `INTERP_STRING_PART`
+    /// when it's cut short by `${`, opening a new interpolation.
+    fn resume_string_segment(&mut self, position: Position) -> (Token, Position) {
+        let token = match self.read_string_segment() {
+            Ok((text, StringSegmentEnd::Closed | StringSegmentEnd::Eof)) => Token::STRING(text),
+            Ok((text, StringSegmentEnd::Interpolation)) => {
+                self.interp_brace_depth.push(0);
+                Token::INTERP_STRING_PART(text)
+            }
+            Err(err) => Token::ILLEGAL(err.to_string()),
+        };
+        (token, position)
+    }
+
+    /// Returns the next token along with the position of its first character.
+    pub fn next_token(&mut self) -> (Token, Position) {
+        self.skip_whitespace();
+
+        let position = Position {
+            line: self.line,
+            column: self.column,
+        };
+
+        let token = match self.ch {
+            '=' => {
+                if self.peek_char() == '=' {
+                    self.read_char();
+                    Token::EQ
+                } else {
+                    Token::ASSIGN
+                }
+            }
+            '!' => {
+                if self.peek_char() == '=' {
+                    self.read_char();
+                    Token::NEQ
+                } else {
+                    Token::BANG
+                }
+            }
+            '+' => Token::PLUS,
+            '-' => Token::MINUS,
+            '*' => {
+                if self.peek_char() == '*' {
+                    self.read_char();
+                    Token::POW
+                } else {
+                    Token::ASTERISK
+                }
+            }
+            '/' => Token::SLASH,
+            '<' => Token::LT,
+            '>' => Token::GT,
+            ',' => Token::COMMA,
+            ';' => Token::SEMICOLON,
+            ':' => Token::COLON,
+            '(' => Token::LPAREN,
+            ')' => Token::RPAREN,
+            '{' => {
+                if let Some(depth) = self.interp_brace_depth.last_mut() {
+                    *depth += 1;
+                }
+                Token::LBRACE
+            }
+            '}' => {
+                if let Some(0) = self.interp_brace_depth.last() {
+                    self.interp_brace_depth.pop();
+                    return self.resume_string_segment(position);
+                }
+                if let Some(depth) = self.interp_brace_depth.last_mut() {
+                    *depth -= 1;
+                }
+                Token::RBRACE
+            }
+            '[' => Token::LBRACKET,
+            ']' => Token::RBRACKET,
+            '"' => return self.resume_string_segment(position),
+            '\'' => {
+                let token = match self.read_char_literal() {
+                    Ok(value) => Token::CHAR(value.to_string()),
+                    Err(err) => Token::ILLEGAL(err.to_string()),
+                };
+                self.read_char();
+                return (token, position);
+            }
+            'b' if self.peek_char() == '"' => {
+                self.read_char();
+                let token = match self.read_byte_string() {
+                    Ok(text) => Token::BYTE_STRING(text),
+                    Err(err) => Token::ILLEGAL(err.to_string()),
+                };
+                return (token, position);
+            }
+            '\0' => Token::EOF,
+            c if c.is_alphabetic() || c == '_' => {
+                let ident = self.read_identifier();
+                return (Token::lookup_ident(&ident), position);
+            }
+            c if c.is_ascii_digit() || (c == '.' && self.peek_char().is_ascii_digit()) => {
+                return match self.read_number() {
+                    Ok((number, true)) => (Token::FLOAT(number), position),
+                    Ok((number, false)) => (Token::INT(number), position),
+                    Err(err) => (Token::ILLEGAL(err.to_string()), position),
+                };
+            }
+            c => Token::ILLEGAL(c.to_string()),
+        };
+
+        self.read_char();
+        (token, position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_token() {
+        let input = "let five = 5;\nlet ten = 10;";
+        let mut lexer = Lexer::new(input);
+
+        let expected = vec![
+            (Token::LET, 1, 1),
+            (Token::IDENT("five".to_string()), 1, 5),
+            (Token::ASSIGN, 1, 10),
+            (Token::INT("5".to_string()), 1, 12),
+            (Token::SEMICOLON, 1, 13),
+            (Token::LET, 2, 1),
+            (Token::IDENT("ten".to_string()), 2, 5),
+            (Token::ASSIGN, 2, 9),
+            (Token::INT("10".to_string()), 2, 11),
+            (Token::SEMICOLON, 2, 13),
+            (Token::EOF, 2, 14),
+        ];
+
+        for (expected_token, line, column) in expected {
+            let (token, position) = lexer.next_token();
+            assert_eq!(token, expected_token);
+            assert_eq!(position, Position { line, column });
+        }
+    }
+
+    #[test]
+    fn test_string_escape_sequences() {
+        let mut lexer = Lexer::new(r#""a\nb\t\"c\"\\\0\u{41}""#);
+        let (token, _) = lexer.next_token();
+        match token {
+            Token::STRING(s) => assert_eq!(s, "a\nb\t\"c\"\\\0A"),
+            other => panic!("expected STRING, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_malformed_escape_sequence_is_illegal() {
+        let mut lexer = Lexer::new(r#""bad\q""#);
+        let (token, _) = lexer.next_token();
+        assert!(matches!(token, Token::ILLEGAL(_)), "expected ILLEGAL, got {:?}", token);
+    }
+
+    #[test]
+    fn test_string_interpolation_boundary_tokens() {
+        let mut lexer = Lexer::new(r#""hi ${name}!""#);
+        let tokens: Vec<Token> = std::iter::from_fn(|| {
+            let (token, _) = lexer.next_token();
+            if token == Token::EOF {
+                None
+            } else {
+                Some(token)
+            }
+        })
+        .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::INTERP_STRING_PART("hi ".to_string()),
+                Token::IDENT("name".to_string()),
+                Token::STRING("!".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_float_literals() {
+        let cases = vec![
+            ("3.14", "3.14"),
+            ("1.0e-3", "1.0e-3"),
+            ("2.5e3", "2.5e3"),
+            ("5.", "5."),
+            (".5", ".5"),
+            ("1e10", "1e10"),
+            ("2.5e-3", "2.5e-3"),
+        ];
+
+        for (input, expected) in cases {
+            let mut lexer = Lexer::new(input);
+            let (token, _) = lexer.next_token();
+            match token {
+                Token::FLOAT(s) => assert_eq!(s, expected, "input: {}", input),
+                other => panic!("expected FLOAT for {:?}, got {:?}", input, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_malformed_number_literal_is_illegal() {
+        let mut lexer = Lexer::new("1.2.3");
+        let (token, _) = lexer.next_token();
+        assert!(matches!(token, Token::ILLEGAL(_)), "expected ILLEGAL, got {:?}", token);
+    }
+
+    #[test]
+    fn test_char_literal() {
+        let mut lexer = Lexer::new(r"'a'");
+        let (token, _) = lexer.next_token();
+        match token {
+            Token::CHAR(s) => assert_eq!(s, "a"),
+            other => panic!("expected CHAR, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_char_literal_escape_sequences() {
+        let cases = vec![
+            (r"'\n'", '\n'),
+            (r"'\t'", '\t'),
+            (r"'\\'", '\\'),
+            (r"'\''", '\''),
+            (r"'\u{41}'", 'A'),
+        ];
+
+        for (input, expected) in cases {
+            let mut lexer = Lexer::new(input);
+            let (token, _) = lexer.next_token();
+            match token {
+                Token::CHAR(s) => assert_eq!(s, expected.to_string(), "input: {}", input),
+                other => panic!("expected CHAR for {:?}, got {:?}", input, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_unterminated_char_literal_is_illegal() {
+        let mut lexer = Lexer::new("'a");
+        let (token, _) = lexer.next_token();
+        assert!(matches!(token, Token::ILLEGAL(_)), "expected ILLEGAL, got {:?}", token);
+    }
+
+    #[test]
+    fn test_overlong_char_literal_is_illegal() {
+        let mut lexer = Lexer::new("'ab'");
+        let (token, _) = lexer.next_token();
+        assert!(matches!(token, Token::ILLEGAL(_)), "expected ILLEGAL, got {:?}", token);
+    }
+
+    #[test]
+    fn test_byte_string_literal() {
+        let mut lexer = Lexer::new(r#"b"hi\n""#);
+        let (token, _) = lexer.next_token();
+        match token {
+            Token::BYTE_STRING(s) => assert_eq!(s, "hi\n"),
+            other => panic!("expected BYTE_STRING, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_identifier_starting_with_b_is_not_a_byte_string() {
+        let mut lexer = Lexer::new("bar");
+        let (token, _) = lexer.next_token();
+        assert_eq!(token, Token::IDENT("bar".to_string()));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod evaluator;
+pub mod lexer;
+pub mod optimizer;
+pub mod parser;
+pub mod token;
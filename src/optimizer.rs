@@ -0,0 +1,315 @@
+//! A constant-folding pass over the parsed AST, modeled on Rhai's
+//! `optimize` module: after `Parser::parse` hands back a `Program`, walk
+//! every expression bottom-up and collapse the subtrees whose value is
+//! already known at parse time.
+
+use crate::parser::expression::{
+    ArrayLiteral, BooleanLiteral, CallExpression, Expression, FunctionLiteral, HashLiteral,
+    IfExpression, InfixExpression, IntegerLiteral, PrefixExpression,
+};
+use crate::parser::program::Program;
+use crate::parser::statement::{BlockStatement, Statement};
+use crate::token::Token;
+
+impl Program {
+    /// Folds constant integer/boolean arithmetic, unary `-`/`!`, and `if`
+    /// expressions with a literal condition. Never folds across an
+    /// `Identifier` or `CallExpression`, since those depend on values only
+    /// known at runtime.
+    pub fn optimize(self) -> Program {
+        Program {
+            statements: self.statements.into_iter().map(optimize_statement).collect(),
+        }
+    }
+}
+
+fn optimize_statement(statement: Statement) -> Statement {
+    match statement {
+        Statement::LetStatement(mut s) => {
+            s.value = s.value.map(optimize_expression);
+            Statement::LetStatement(s)
+        }
+        Statement::ReturnStatement(mut s) => {
+            s.value = s.value.map(optimize_expression);
+            Statement::ReturnStatement(s)
+        }
+        Statement::ExpressionStatement(mut s) => {
+            s.expression = s.expression.map(optimize_expression);
+            Statement::ExpressionStatement(s)
+        }
+    }
+}
+
+fn optimize_block(block: BlockStatement) -> BlockStatement {
+    BlockStatement {
+        token: block.token,
+        statements: block.statements.into_iter().map(optimize_statement).collect(),
+    }
+}
+
+fn optimize_expression(expression: Expression) -> Expression {
+    match expression {
+        Expression::PrefixExpression(p) => optimize_prefix(p),
+        Expression::InfixExpression(i) => optimize_infix(i),
+        Expression::IfExpression(i) => optimize_if(i),
+        Expression::FunctionLiteral(fl) => Expression::FunctionLiteral(FunctionLiteral {
+            token: fl.token,
+            parameters: fl.parameters,
+            body: optimize_block(fl.body),
+        }),
+        Expression::CallExpression(c) => Expression::CallExpression(CallExpression {
+            token: c.token,
+            function: c.function,
+            args: c.args.into_iter().map(optimize_expression).collect(),
+        }),
+        Expression::ArrayLiteral(a) => Expression::ArrayLiteral(ArrayLiteral {
+            token: a.token,
+            elements: a.elements.into_iter().map(optimize_expression).collect(),
+        }),
+        Expression::HashLiteral(h) => Expression::HashLiteral(HashLiteral {
+            token: h.token,
+            paris: h
+                .paris
+                .into_iter()
+                .map(|(k, v)| (optimize_expression(k), optimize_expression(v)))
+                .collect(),
+        }),
+        other => other,
+    }
+}
+
+fn optimize_prefix(prefix: PrefixExpression) -> Expression {
+    let right = optimize_expression(*prefix.right);
+
+    match (&prefix.token, &right) {
+        (Token::MINUS, Expression::IntegerLiteral(lit)) => {
+            if let Some(value) = lit.value.checked_neg() {
+                return int_literal(value);
+            }
+        }
+        (Token::MINUS, Expression::FloatLiteral(lit)) => {
+            return float_literal(-lit.value);
+        }
+        (Token::BANG, Expression::BooleanLiteral(lit)) => {
+            return bool_literal(!lit.value);
+        }
+        _ => {}
+    }
+
+    Expression::PrefixExpression(PrefixExpression {
+        token: prefix.token,
+        right: Box::new(right),
+    })
+}
+
+fn optimize_infix(infix: InfixExpression) -> Expression {
+    let left = optimize_expression(*infix.left);
+    let right = optimize_expression(*infix.right);
+
+    if let (Expression::IntegerLiteral(l), Expression::IntegerLiteral(r)) = (&left, &right) {
+        if let Some(folded) = fold_integer_infix(&infix.token, l.value, r.value) {
+            return folded;
+        }
+    }
+
+    if let (Expression::BooleanLiteral(l), Expression::BooleanLiteral(r)) = (&left, &right) {
+        if let Some(folded) = fold_boolean_infix(&infix.token, l.value, r.value) {
+            return folded;
+        }
+    }
+
+    Expression::InfixExpression(InfixExpression {
+        token: infix.token,
+        left: Box::new(left),
+        right: Box::new(right),
+    })
+}
+
+/// Returns `None` (leaving the original node in place) on division by zero
+/// or `i64` overflow, rather than folding to a wrong or panicking value.
+fn fold_integer_infix(token: &Token, left: i64, right: i64) -> Option<Expression> {
+    match token {
+        Token::PLUS => left.checked_add(right).map(int_literal),
+        Token::MINUS => left.checked_sub(right).map(int_literal),
+        Token::ASTERISK => left.checked_mul(right).map(int_literal),
+        Token::SLASH => {
+            if right == 0 {
+                None
+            } else {
+                left.checked_div(right).map(int_literal)
+            }
+        }
+        Token::EQ => Some(bool_literal(left == right)),
+        Token::NEQ => Some(bool_literal(left != right)),
+        Token::LT => Some(bool_literal(left < right)),
+        Token::GT => Some(bool_literal(left > right)),
+        _ => None,
+    }
+}
+
+fn fold_boolean_infix(token: &Token, left: bool, right: bool) -> Option<Expression> {
+    match token {
+        Token::EQ => Some(bool_literal(left == right)),
+        Token::NEQ => Some(bool_literal(left != right)),
+        _ => None,
+    }
+}
+
+/// An `if` whose condition folds to a literal `true`/`false` is replaced by
+/// the chosen branch only when that branch is a single expression
+/// statement, which is inlined down to the bare expression. Otherwise (a
+/// multi-statement branch, or `false` with no `else`) the `if` is kept with
+/// its folded condition, since an `Expression` slot can't hold an arbitrary
+/// `BlockStatement`.
+fn optimize_if(if_exp: IfExpression) -> Expression {
+    let condition = optimize_expression(*if_exp.condition);
+    let consequence = optimize_block(if_exp.consequence);
+    let alternative = if_exp.alternative.map(optimize_block);
+
+    if let Expression::BooleanLiteral(cond) = &condition {
+        let chosen = if cond.value {
+            Some(&consequence)
+        } else {
+            alternative.as_ref()
+        };
+
+        if let Some(block) = chosen {
+            if let [Statement::ExpressionStatement(stmt)] = block.statements.as_slice() {
+                if let Some(expr) = &stmt.expression {
+                    return expr.clone();
+                }
+            }
+        }
+    }
+
+    Expression::IfExpression(IfExpression {
+        token: if_exp.token,
+        condition: Box::new(condition),
+        consequence,
+        alternative,
+    })
+}
+
+fn int_literal(value: i64) -> Expression {
+    Expression::IntegerLiteral(IntegerLiteral {
+        token: Token::INT(value.to_string()),
+        value,
+    })
+}
+
+fn float_literal(value: f64) -> Expression {
+    Expression::FloatLiteral(crate::parser::expression::FloatLiteral {
+        token: Token::FLOAT(value.to_string()),
+        value,
+    })
+}
+
+fn bool_literal(value: bool) -> Expression {
+    Expression::BooleanLiteral(BooleanLiteral {
+        token: if value { Token::TRUE } else { Token::FALSE },
+        value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lexer::Lexer;
+    use crate::parser::expression::Expression;
+    use crate::parser::statement::Statement;
+    use crate::parser::Parser;
+
+    fn optimized(input: &str) -> crate::parser::program::Program {
+        Parser::new(Lexer::new(input))
+            .parse()
+            .unwrap_or_else(|errors| panic!("parser errors: {:?}", errors))
+            .optimize()
+    }
+
+    fn only_expression(program: &crate::parser::program::Program) -> &Expression {
+        match &program.statements[0] {
+            Statement::ExpressionStatement(s) => s.expression.as_ref().unwrap(),
+            other => panic!("expected expression statement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn folds_integer_arithmetic() {
+        let program = optimized("5 + 5;");
+        match only_expression(&program) {
+            Expression::IntegerLiteral(lit) => assert_eq!(lit.value, 10),
+            other => panic!("expected folded IntegerLiteral, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn folds_integer_comparison_to_bool() {
+        let program = optimized("3 > 5;");
+        match only_expression(&program) {
+            Expression::BooleanLiteral(lit) => assert!(!lit.value),
+            other => panic!("expected folded BooleanLiteral, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn folds_prefix_negation() {
+        let program = optimized("-5;");
+        match only_expression(&program) {
+            Expression::IntegerLiteral(lit) => assert_eq!(lit.value, -5),
+            other => panic!("expected folded IntegerLiteral, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn skips_fold_on_division_by_zero() {
+        let program = optimized("5 / 0;");
+        match only_expression(&program) {
+            Expression::InfixExpression(_) => {}
+            other => panic!("expected unfolded InfixExpression, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn skips_fold_on_overflow() {
+        let program = optimized("9223372036854775807 + 1;");
+        match only_expression(&program) {
+            Expression::InfixExpression(_) => {}
+            other => panic!("expected unfolded InfixExpression, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn never_folds_across_identifiers() {
+        let program = optimized("5 + x;");
+        match only_expression(&program) {
+            Expression::InfixExpression(_) => {}
+            other => panic!("expected unfolded InfixExpression, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn inlines_constant_if_branch() {
+        let program = optimized("if (true) { 1 } else { 2 }");
+        match only_expression(&program) {
+            Expression::IntegerLiteral(lit) => assert_eq!(lit.value, 1),
+            other => panic!("expected folded IntegerLiteral, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn folds_nested_constants_in_array() {
+        let program = optimized("[1 + 1, 2 + 2];");
+        match only_expression(&program) {
+            Expression::ArrayLiteral(a) => {
+                match &a.elements[0] {
+                    Expression::IntegerLiteral(lit) => assert_eq!(lit.value, 2),
+                    other => panic!("expected folded element, got {:?}", other),
+                }
+                match &a.elements[1] {
+                    Expression::IntegerLiteral(lit) => assert_eq!(lit.value, 4),
+                    other => panic!("expected folded element, got {:?}", other),
+                }
+            }
+            other => panic!("expected ArrayLiteral, got {:?}", other),
+        }
+    }
+}
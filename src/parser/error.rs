@@ -0,0 +1,77 @@
+use std::fmt;
+
+use crate::lexer::Position;
+use crate::token::Token;
+
+/// A typed parse failure with the source position of the offending token,
+/// replacing the old `Vec<String>` of ad-hoc messages.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    VarExpectsIdentifier { found: Token, position: Position },
+    VarExpectsAssign { found: Token, position: Position },
+    IfMissingLeftParen { found: Token, position: Position },
+    IfMissingRightParen { found: Token, position: Position },
+    IfMissingLeftBrace { found: Token, position: Position },
+    FnMissingLeftParen { found: Token, position: Position },
+    FnMissingParams { found: Token, position: Position },
+    FnMissingLeftBrace { found: Token, position: Position },
+    MissingRightParen { found: Token, position: Position },
+    MissingRightBracket { found: Token, position: Position },
+    HashMissingColon { found: Token, position: Position },
+    HashMissingCommaOrRightBrace { found: Token, position: Position },
+    NoPrefixParseFn { found: Token, position: Position },
+    MalformedNumber { literal: String, position: Position },
+    UnterminatedStringInterpolation { found: Token, position: Position },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::VarExpectsIdentifier { found, position } => {
+                write!(f, "{}: expected identifier after 'let', found {}", position, found)
+            }
+            ParseError::VarExpectsAssign { found, position } => {
+                write!(f, "{}: expected '=' after let identifier, found {}", position, found)
+            }
+            ParseError::IfMissingLeftParen { found, position } => {
+                write!(f, "{}: expected '(' after 'if', found {}", position, found)
+            }
+            ParseError::IfMissingRightParen { found, position } => {
+                write!(f, "{}: expected ')' to close if condition, found {}", position, found)
+            }
+            ParseError::IfMissingLeftBrace { found, position } => {
+                write!(f, "{}: expected '{{' to start block, found {}", position, found)
+            }
+            ParseError::FnMissingLeftParen { found, position } => {
+                write!(f, "{}: expected '(' after 'fn', found {}", position, found)
+            }
+            ParseError::FnMissingParams { found, position } => {
+                write!(f, "{}: expected identifier or ')' in parameter list, found {}", position, found)
+            }
+            ParseError::FnMissingLeftBrace { found, position } => {
+                write!(f, "{}: expected '{{' to start function body, found {}", position, found)
+            }
+            ParseError::MissingRightParen { found, position } => {
+                write!(f, "{}: expected ')', found {}", position, found)
+            }
+            ParseError::MissingRightBracket { found, position } => {
+                write!(f, "{}: expected ']', found {}", position, found)
+            }
+            ParseError::HashMissingColon { found, position } => {
+                write!(f, "{}: expected ':' after hash key, found {}", position, found)
+            }
+            ParseError::HashMissingCommaOrRightBrace { found, position } => {
+                write!(f, "{}: expected ',' or '}}' after hash value, found {}", position, found)
+            }
+            ParseError::NoPrefixParseFn { found, position } => {
+                write!(f, "{}: no prefix parse function for {}", position, found)
+            }
+            ParseError::MalformedNumber { literal, position } => {
+                write!(f, "{}: could not parse '{}' as a number", position, literal)
+            }
+            ParseError::UnterminatedStringInterpolation { found, position } => {
+                write!(f, "{}: expected '}}' to close string interpolation, found {}", position, found)
+            }
+        }
+    }
+}
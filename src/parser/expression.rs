@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::parser::node::Node;
+use crate::parser::statement::BlockStatement;
+use crate::token::Token;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expression {
+    Identifier(Identifier),
+    IntegerLiteral(IntegerLiteral),
+    FloatLiteral(FloatLiteral),
+    BooleanLiteral(BooleanLiteral),
+    StringLiteral(StringLiteral),
+    CharLiteral(CharLiteral),
+    ByteStringLiteral(ByteStringLiteral),
+    PrefixExpression(PrefixExpression),
+    InfixExpression(InfixExpression),
+    IfExpression(IfExpression),
+    FunctionLiteral(FunctionLiteral),
+    CallExpression(CallExpression),
+    ArrayLiteral(ArrayLiteral),
+    HashLiteral(HashLiteral),
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expression::Identifier(e) => write!(f, "{}", e),
+            Expression::IntegerLiteral(e) => write!(f, "{}", e),
+            Expression::FloatLiteral(e) => write!(f, "{}", e),
+            Expression::BooleanLiteral(e) => write!(f, "{}", e),
+            Expression::StringLiteral(e) => write!(f, "{}", e),
+            Expression::CharLiteral(e) => write!(f, "{}", e),
+            Expression::ByteStringLiteral(e) => write!(f, "{}", e),
+            Expression::PrefixExpression(e) => write!(f, "{}", e),
+            Expression::InfixExpression(e) => write!(f, "{}", e),
+            Expression::IfExpression(e) => write!(f, "{}", e),
+            Expression::FunctionLiteral(e) => write!(f, "{}", e),
+            Expression::CallExpression(e) => write!(f, "{}", e),
+            Expression::ArrayLiteral(e) => write!(f, "{}", e),
+            Expression::HashLiteral(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Node for Expression {}
+
+// `HashLiteral` embeds a `HashMap`, which has no `Hash` impl of its own, so
+// `Expression` can't just `#[derive(Hash)]`. Hash on the rendered source text
+// instead, which is already unique enough to key a `HashMap<Expression, _>`.
+impl std::hash::Hash for Expression {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.to_string().hash(state);
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Identifier {
+    pub token: Token,
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.token.literal())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct IntegerLiteral {
+    pub token: Token,
+    pub value: i64,
+}
+
+impl fmt::Display for IntegerLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloatLiteral {
+    pub token: Token,
+    pub value: f64,
+}
+
+impl fmt::Display for FloatLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+// `f64` has no total order (NaN != NaN), so this can't derive `Eq`. Literals
+// parsed from source text are never NaN (an out-of-range literal such as
+// `1e999` parses to infinity, which still equals itself), so treating them as `Eq` here
+// is safe in practice — the same tradeoff `ordered-float` makes.
+impl Eq for FloatLiteral {}
+
+impl std::hash::Hash for FloatLiteral {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.token.hash(state);
+        self.value.to_bits().hash(state);
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BooleanLiteral {
+    pub token: Token,
+    pub value: bool,
+}
+
+impl fmt::Display for BooleanLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct StringLiteral {
+    pub token: Token,
+    pub value: String,
+}
+
+impl fmt::Display for StringLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CharLiteral {
+    pub token: Token,
+    pub value: char,
+}
+
+impl fmt::Display for CharLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ByteStringLiteral {
+    pub token: Token,
+    pub value: Vec<u8>,
+}
+
+impl fmt::Display for ByteStringLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.token.literal())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PrefixExpression {
+    pub token: Token,
+    pub right: Box<Expression>,
+}
+
+impl fmt::Display for PrefixExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}{})", self.token.literal(), self.right)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct InfixExpression {
+    pub token: Token,
+    pub left: Box<Expression>,
+    pub right: Box<Expression>,
+}
+
+impl fmt::Display for InfixExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({} {} {})",
+            self.left,
+            self.token.literal(),
+            self.right
+        )
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct IfExpression {
+    pub token: Token,
+    pub condition: Box<Expression>,
+    pub consequence: BlockStatement,
+    pub alternative: Option<BlockStatement>,
+}
+
+impl fmt::Display for IfExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "if{} {}", self.condition, self.consequence)?;
+        if let Some(alternative) = &self.alternative {
+            write!(f, "else {}", alternative)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct FunctionLiteral {
+    pub token: Token,
+    pub parameters: Vec<Identifier>,
+    pub body: BlockStatement,
+}
+
+impl fmt::Display for FunctionLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let params: Vec<String> = self.parameters.iter().map(|p| p.to_string()).collect();
+        write!(
+            f,
+            "{}({}) {}",
+            self.token.literal(),
+            params.join(", "),
+            self.body
+        )
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CallExpression {
+    pub token: Token,
+    pub function: Box<Expression>,
+    pub args: Vec<Expression>,
+}
+
+impl fmt::Display for CallExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args: Vec<String> = self.args.iter().map(|a| a.to_string()).collect();
+        write!(f, "{}({})", self.function, args.join(", "))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ArrayLiteral {
+    pub token: Token,
+    pub elements: Vec<Expression>,
+}
+
+impl fmt::Display for ArrayLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elements: Vec<String> = self.elements.iter().map(|e| e.to_string()).collect();
+        write!(f, "[{}]", elements.join(", "))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HashLiteral {
+    pub token: Token,
+    pub paris: HashMap<Expression, Expression>,
+}
+
+impl fmt::Display for HashLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pairs: Vec<String> = self
+            .paris
+            .iter()
+            .map(|(k, v)| format!("{}: {}", k, v))
+            .collect();
+        write!(f, "{{{}}}", pairs.join(", "))
+    }
+}
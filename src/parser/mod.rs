@@ -1,72 +1,214 @@
 use std::collections::HashMap;
 use std::mem::swap;
 
-use crate::lexer::Lexer;
+use crate::lexer::{Lexer, Position};
+use crate::parser::error::ParseError;
 use crate::parser::expression::{
-    ArrayLiteral, BooleanLiteral, CallExpression, Expression, FunctionLiteral, HashLiteral,
-    Identifier, IfExpression, InfixExpression, IntegerLiteral, PrefixExpression, StringLiteral,
+    ArrayLiteral, BooleanLiteral, ByteStringLiteral, CallExpression, CharLiteral, Expression,
+    FloatLiteral, FunctionLiteral, HashLiteral, Identifier, IfExpression, InfixExpression,
+    IntegerLiteral, PrefixExpression, StringLiteral,
 };
 use crate::parser::program::Program;
 use crate::parser::statement::{
     BlockStatement, ExpressionStatement, LetStatement, ReturnStatement, Statement,
 };
-use crate::token::Token;
+use crate::token::{Token, TokenKind};
 
+pub mod error;
 pub mod expression;
 pub mod node;
 pub mod program;
 pub mod statement;
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
-enum PrecedenceType {
+pub enum PrecedenceType {
     LOWEST,
     EQUALS,
     LESSGREATER,
     SUM,
     PRODUCT,
+    EXPONENT,
     PREFIX,
     CALL,
 }
 
+/// Whether an infix operator groups with operators of its own precedence to
+/// its left (`a + b + c` as `(a + b) + c`) or to its right (`a ** b ** c` as
+/// `a ** (b ** c)`). Looked up per-operator so the Pratt loop can decide,
+/// for each peeked operator, whether to keep binding at the current
+/// precedence level or stop.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
+/// A prefix parse function consumes no token of its own before being
+/// called — `self.cur_token` is already positioned on the literal/operator
+/// it handles.
+pub type PrefixParseFn = fn(&mut Parser) -> Option<Expression>;
+
+/// An infix parse function is called with `self.cur_token` positioned on
+/// the infix operator and `left` holding the already-parsed left operand.
+pub type InfixParseFn = fn(&mut Parser, Expression) -> Option<Expression>;
+
 pub struct Parser {
     lexer: Lexer,
-    errors: Vec<String>,
+    errors: Vec<ParseError>,
     cur_token: Token,
+    cur_pos: Position,
     peek_token: Token,
+    peek_pos: Position,
+    prefix_parse_fns: HashMap<TokenKind, PrefixParseFn>,
+    infix_parse_fns: HashMap<TokenKind, InfixParseFn>,
+    precedences: HashMap<TokenKind, PrecedenceType>,
+    associativities: HashMap<TokenKind, Associativity>,
 }
 
 impl Parser {
     pub fn new(mut lexer: Lexer) -> Self {
-        let cur_token = lexer.next_token();
-        let peek_token = lexer.next_token();
-        Parser {
+        let (cur_token, cur_pos) = lexer.next_token();
+        let (peek_token, peek_pos) = lexer.next_token();
+        let mut parser = Parser {
             lexer,
             errors: Vec::new(),
             cur_token,
+            cur_pos,
             peek_token,
-        }
+            peek_pos,
+            prefix_parse_fns: HashMap::new(),
+            infix_parse_fns: HashMap::new(),
+            precedences: HashMap::new(),
+            associativities: HashMap::new(),
+        };
+
+        parser.register_prefix(TokenKind::IDENT, Parser::parse_identifier);
+        parser.register_prefix(TokenKind::INT, Parser::parse_integer_literal);
+        parser.register_prefix(TokenKind::FLOAT, Parser::parse_float_literal);
+        parser.register_prefix(TokenKind::TRUE, Parser::parse_bool_literal);
+        parser.register_prefix(TokenKind::FALSE, Parser::parse_bool_literal);
+        parser.register_prefix(TokenKind::LPAREN, Parser::parse_grouped_expression);
+        parser.register_prefix(TokenKind::IF, Parser::parse_if_expression);
+        parser.register_prefix(TokenKind::FUNCTION, Parser::parse_fn_expression);
+        parser.register_prefix(TokenKind::LBRACKET, Parser::parse_array_literal);
+        parser.register_prefix(TokenKind::LBRACE, Parser::parse_hash_literal);
+        parser.register_prefix(TokenKind::BANG, Parser::parse_prefix_expression);
+        parser.register_prefix(TokenKind::MINUS, Parser::parse_prefix_expression);
+        parser.register_prefix(TokenKind::STRING, Parser::parse_string_literal);
+        parser.register_prefix(TokenKind::INTERP_STRING_PART, Parser::parse_string_literal);
+        parser.register_prefix(TokenKind::CHAR, Parser::parse_char_literal);
+        parser.register_prefix(TokenKind::BYTE_STRING, Parser::parse_byte_string_literal);
+
+        parser.register_infix(TokenKind::PLUS, PrecedenceType::SUM, Associativity::Left, Parser::parse_infix_expression);
+        parser.register_infix(TokenKind::MINUS, PrecedenceType::SUM, Associativity::Left, Parser::parse_infix_expression);
+        parser.register_infix(TokenKind::SLASH, PrecedenceType::PRODUCT, Associativity::Left, Parser::parse_infix_expression);
+        parser.register_infix(TokenKind::ASTERISK, PrecedenceType::PRODUCT, Associativity::Left, Parser::parse_infix_expression);
+        parser.register_infix(TokenKind::POW, PrecedenceType::EXPONENT, Associativity::Right, Parser::parse_infix_expression);
+        parser.register_infix(TokenKind::EQ, PrecedenceType::EQUALS, Associativity::Left, Parser::parse_infix_expression);
+        parser.register_infix(TokenKind::NEQ, PrecedenceType::EQUALS, Associativity::Left, Parser::parse_infix_expression);
+        parser.register_infix(TokenKind::LT, PrecedenceType::LESSGREATER, Associativity::Left, Parser::parse_infix_expression);
+        parser.register_infix(TokenKind::GT, PrecedenceType::LESSGREATER, Associativity::Left, Parser::parse_infix_expression);
+        parser.register_infix(TokenKind::LPAREN, PrecedenceType::CALL, Associativity::Left, Parser::parse_call_expression);
+
+        parser
+    }
+
+    /// Registers (or overrides) the prefix parse function for `kind`, so
+    /// embedders can add new literal/prefix syntax without editing the
+    /// core dispatch.
+    pub fn register_prefix(&mut self, kind: TokenKind, parse_fn: PrefixParseFn) {
+        self.prefix_parse_fns.insert(kind, parse_fn);
+    }
+
+    /// Registers (or overrides) the infix parse function, precedence, and
+    /// associativity for `kind`, so embedders can add new binary operators
+    /// (e.g. `%`, `&&`) without editing the core dispatch. Must be called
+    /// before `parse`/`parse_program` run, since the Pratt loop reads these
+    /// tables as it goes.
+    pub fn register_infix(
+        &mut self,
+        kind: TokenKind,
+        precedence: PrecedenceType,
+        associativity: Associativity,
+        parse_fn: InfixParseFn,
+    ) {
+        self.precedences.insert(kind, precedence);
+        self.associativities.insert(kind, associativity);
+        self.infix_parse_fns.insert(kind, parse_fn);
     }
 
     fn next_token(&mut self) {
         swap(&mut self.cur_token, &mut self.peek_token);
-        self.peek_token = self.lexer.next_token();
+        self.cur_pos = self.peek_pos;
+        let (peek_token, peek_pos) = self.lexer.next_token();
+        self.peek_token = peek_token;
+        self.peek_pos = peek_pos;
     }
 
+    /// Parses the whole input, returning every statement that parsed
+    /// cleanly. A statement that fails to parse is dropped, but doesn't
+    /// stop the pass: its error is recorded in `self.errors` and
+    /// `synchronize` skips ahead to the next likely statement boundary so
+    /// parsing can resume, collecting further independent diagnostics in
+    /// the same call. Use [`Parser::parse`] if a single `Result` is more
+    /// convenient and the partial `Program` isn't needed when there are
+    /// errors.
     pub fn parse_program(&mut self) -> Program {
         let mut program = Program {
             statements: Vec::new(),
         };
 
         while !matches!(self.cur_token, Token::EOF) {
-            if let Some(statement) = self.parse_statement() {
-                program.statements.push(statement);
+            let errors_before = self.errors.len();
+            match self.parse_statement() {
+                Some(statement) if self.errors.len() == errors_before => {
+                    program.statements.push(statement);
+                    self.next_token();
+                }
+                _ => self.synchronize(),
             }
-            self.next_token();
         }
 
         program
     }
 
+    /// Parses the whole input into a `Program`, or every collected
+    /// `ParseError` if any statement failed.
+    pub fn parse(&mut self) -> Result<Program, Vec<ParseError>> {
+        let program = self.parse_program();
+        if self.errors.is_empty() {
+            Ok(program)
+        } else {
+            Err(self.errors.clone())
+        }
+    }
+
+    /// The diagnostics collected so far. Most useful alongside
+    /// [`Parser::parse_program`], which keeps every cleanly-parsed
+    /// statement even when this is non-empty.
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+
+    /// Panic-mode recovery after a statement fails to parse: skip past the
+    /// token that caused the failure, then keep skipping until a likely
+    /// statement boundary — a `;` (consumed, so the next statement starts
+    /// fresh) or a `let`/`return`/`}`/EOF (left in place, since each of
+    /// those already starts or ends a statement/block on its own).
+    fn synchronize(&mut self) {
+        self.next_token();
+        while !matches!(
+            self.cur_token,
+            Token::SEMICOLON | Token::LET | Token::RETURN | Token::RBRACE | Token::EOF
+        ) {
+            self.next_token();
+        }
+        if matches!(self.cur_token, Token::SEMICOLON) {
+            self.next_token();
+        }
+    }
+
     fn parse_statement(&mut self) -> Option<Statement> {
         match &self.cur_token {
             Token::LET => self.parse_let_statement(),
@@ -81,10 +223,10 @@ impl Parser {
         if matches!(self.peek_token, Token::IDENT(_)) {
             self.next_token()
         } else {
-            self.errors.push(format!(
-                "expected next token to be Token::IDENT, got {} instead",
-                self.peek_token
-            ));
+            self.errors.push(ParseError::VarExpectsIdentifier {
+                found: self.peek_token.clone(),
+                position: self.peek_pos,
+            });
             return None;
         }
 
@@ -95,10 +237,10 @@ impl Parser {
         if matches!(self.peek_token, Token::ASSIGN) {
             self.next_token()
         } else {
-            self.errors.push(format!(
-                "expected next token to be Token::ASSIGN, got {} instead",
-                self.peek_token
-            ));
+            self.errors.push(ParseError::VarExpectsAssign {
+                found: self.peek_token.clone(),
+                position: self.peek_pos,
+            });
             return None;
         }
 
@@ -147,99 +289,196 @@ impl Parser {
 
         self.next_token();
         while !matches!(self.cur_token, Token::RBRACE | Token::EOF) {
-            let stmt = self.parse_statement();
-            if let Some(s) = stmt {
-                statements.push(s)
+            match self.parse_statement() {
+                Some(statement) => {
+                    statements.push(statement);
+                    self.next_token();
+                }
+                None => self.synchronize(),
             }
-            self.next_token();
         }
 
         BlockStatement { token, statements }
     }
 
     fn parse_expression(&mut self, precedence: PrecedenceType) -> Option<Expression> {
-        let mut left = match &self.cur_token {
-            Token::IDENT(_) => self.parse_identifier(),
-            Token::INT(int_string) => self.parse_integer_literal(int_string.to_string()),
-            Token::TRUE => self.parse_bool_literal(true),
-            Token::FALSE => self.parse_bool_literal(false),
-            Token::LPAREN => self.parse_grouped_expression(),
-            Token::IF => self.parse_if_expression(),
-            Token::FUNCTION => self.parse_fn_expression(),
-            Token::LBRACKET => self.parse_array_literal(),
-            Token::LBRACE => self.parse_hash_literal(),
-            Token::BANG => self.parse_prefix_expression(),
-            Token::MINUS => self.parse_prefix_expression(),
-            Token::STRING(s) => self.parse_string_literal(),
-            _ => {
-                self.errors.push(format!(
-                    "no prefix parse function for {:?} found",
-                    self.cur_token
-                ));
-                None
-            }
-        }?;
-
-        while !matches!(self.peek_token, Token::SEMICOLON)
-            && precedence < Self::get_precedence(&self.peek_token)
-        {
-            if matches!(
-                self.peek_token,
-                Token::PLUS
-                    | Token::MINUS
-                    | Token::SLASH
-                    | Token::ASTERISK
-                    | Token::EQ
-                    | Token::NEQ
-                    | Token::LT
-                    | Token::GT
-            ) {
-                self.next_token();
-                left = self.parse_infix_expression(left)?;
-            } else if matches!(self.peek_token, Token::LPAREN) {
-                self.next_token();
-                left = self.parse_call_expression(left)?;
-            } else {
-                break;
+        let prefix_fn = match self.prefix_parse_fns.get(&self.cur_token.kind()) {
+            Some(prefix_fn) => *prefix_fn,
+            None => {
+                self.errors.push(ParseError::NoPrefixParseFn {
+                    found: self.cur_token.clone(),
+                    position: self.cur_pos,
+                });
+                return None;
             }
+        };
+        let mut left = prefix_fn(self)?;
+
+        while !matches!(self.peek_token, Token::SEMICOLON) && self.should_bind_peek(precedence) {
+            let infix_fn = match self.infix_parse_fns.get(&self.peek_token.kind()) {
+                Some(infix_fn) => *infix_fn,
+                None => break,
+            };
+            self.next_token();
+            left = infix_fn(self, left)?;
         }
 
         Some(left)
     }
 
+    fn peek_precedence(&self) -> PrecedenceType {
+        self.precedences
+            .get(&self.peek_token.kind())
+            .copied()
+            .unwrap_or(PrecedenceType::LOWEST)
+    }
+
+    fn peek_associativity(&self) -> Associativity {
+        self.associativities
+            .get(&self.peek_token.kind())
+            .copied()
+            .unwrap_or(Associativity::Left)
+    }
+
+    /// Whether the loop in `parse_expression` should keep binding the
+    /// peeked operator at the current precedence level. Left-associative
+    /// operators require strictly higher peek precedence (so `a + b + c`
+    /// groups as `(a + b) + c`); right-associative operators also bind at
+    /// equal precedence (so `a ** b ** c` groups as `a ** (b ** c)`).
+    fn should_bind_peek(&self, precedence: PrecedenceType) -> bool {
+        match self.peek_associativity() {
+            Associativity::Left => precedence < self.peek_precedence(),
+            Associativity::Right => precedence <= self.peek_precedence(),
+        }
+    }
+
     fn parse_identifier(&mut self) -> Option<Expression> {
         Some(Expression::Identifier(Identifier {
             token: self.cur_token.clone(),
         }))
     }
 
-    fn parse_integer_literal(&mut self, int_string: String) -> Option<Expression> {
+    fn parse_integer_literal(&mut self) -> Option<Expression> {
         let token = self.cur_token.clone();
-        let value_result = int_string.parse::<i64>();
-        match value_result {
+        let int_string = token.literal().to_string();
+        match int_string.parse::<i64>() {
             Ok(value) => Some(Expression::IntegerLiteral(IntegerLiteral { token, value })),
             Err(_) => {
-                self.errors
-                    .push(format!("count not parse {:?} as integer", token));
+                self.errors.push(ParseError::MalformedNumber {
+                    literal: int_string,
+                    position: self.cur_pos,
+                });
+                None
+            }
+        }
+    }
+
+    fn parse_float_literal(&mut self) -> Option<Expression> {
+        let token = self.cur_token.clone();
+        let float_string = token.literal().to_string();
+        match float_string.parse::<f64>() {
+            Ok(value) => Some(Expression::FloatLiteral(FloatLiteral { token, value })),
+            Err(_) => {
+                self.errors.push(ParseError::MalformedNumber {
+                    literal: float_string,
+                    position: self.cur_pos,
+                });
                 None
             }
         }
     }
 
-    fn parse_bool_literal(&mut self, value: bool) -> Option<Expression> {
+    fn parse_bool_literal(&mut self) -> Option<Expression> {
         Some(Expression::BooleanLiteral(BooleanLiteral {
+            value: matches!(self.cur_token, Token::TRUE),
             token: self.cur_token.clone(),
-            value,
         }))
     }
 
     fn parse_string_literal(&mut self) -> Option<Expression> {
+        if let Token::INTERP_STRING_PART(first_segment) = self.cur_token.clone() {
+            return self.parse_string_interpolation(first_segment);
+        }
+
         Some(Expression::StringLiteral(StringLiteral {
             token: self.cur_token.clone(),
             value: self.cur_token.literal().to_string(),
         }))
     }
 
+    /// Desugars `"hi ${name}!"` into `("hi " + name) + "!"` — a left-to-right
+    /// chain of `InfixExpression` `+` over the literal segments and the
+    /// parsed sub-expressions between them. `self.cur_token` is the
+    /// `INTERP_STRING_PART` that starts the chain; the lexer resumes string
+    /// scanning right after each interpolation's closing `}`, so by the time
+    /// a sub-expression finishes parsing, `peek_token` already holds the
+    /// next segment.
+    fn parse_string_interpolation(&mut self, first_segment: String) -> Option<Expression> {
+        let mut left = Expression::StringLiteral(StringLiteral {
+            token: self.cur_token.clone(),
+            value: first_segment,
+        });
+
+        loop {
+            self.next_token();
+            let expr = self.parse_expression(PrecedenceType::LOWEST)?;
+            left = Expression::InfixExpression(InfixExpression {
+                token: Token::PLUS,
+                left: Box::new(left),
+                right: Box::new(expr),
+            });
+
+            self.next_token();
+            match self.cur_token.clone() {
+                Token::STRING(segment) => {
+                    left = Expression::InfixExpression(InfixExpression {
+                        token: Token::PLUS,
+                        left: Box::new(left),
+                        right: Box::new(Expression::StringLiteral(StringLiteral {
+                            token: self.cur_token.clone(),
+                            value: segment,
+                        })),
+                    });
+                    return Some(left);
+                }
+                Token::INTERP_STRING_PART(segment) => {
+                    left = Expression::InfixExpression(InfixExpression {
+                        token: Token::PLUS,
+                        left: Box::new(left),
+                        right: Box::new(Expression::StringLiteral(StringLiteral {
+                            token: self.cur_token.clone(),
+                            value: segment,
+                        })),
+                    });
+                }
+                _ => {
+                    self.errors.push(ParseError::UnterminatedStringInterpolation {
+                        found: self.cur_token.clone(),
+                        position: self.cur_pos,
+                    });
+                    return None;
+                }
+            }
+        }
+    }
+
+    /// The lexer already decoded the literal down to a single scalar value
+    /// (rejecting empty/unterminated/over-long char literals itself), so
+    /// this just wraps it.
+    fn parse_char_literal(&mut self) -> Option<Expression> {
+        let token = self.cur_token.clone();
+        let value = token.literal().chars().next().unwrap_or('\0');
+        Some(Expression::CharLiteral(CharLiteral { token, value }))
+    }
+
+    /// The lexer hands back the byte string's decoded text as UTF-8; this
+    /// is where it actually becomes the `Vec<u8>` the literal represents.
+    fn parse_byte_string_literal(&mut self) -> Option<Expression> {
+        let token = self.cur_token.clone();
+        let value = token.literal().bytes().collect();
+        Some(Expression::ByteStringLiteral(ByteStringLiteral { token, value }))
+    }
+
     fn parse_grouped_expression(&mut self) -> Option<Expression> {
         self.next_token();
 
@@ -248,6 +487,10 @@ impl Parser {
             self.next_token();
             exp
         } else {
+            self.errors.push(ParseError::MissingRightParen {
+                found: self.peek_token.clone(),
+                position: self.peek_pos,
+            });
             None
         }
     }
@@ -258,6 +501,10 @@ impl Parser {
         if matches!(self.peek_token, Token::LPAREN) {
             self.next_token();
         } else {
+            self.errors.push(ParseError::IfMissingLeftParen {
+                found: self.peek_token.clone(),
+                position: self.peek_pos,
+            });
             return None;
         }
 
@@ -267,12 +514,20 @@ impl Parser {
         if matches!(self.peek_token, Token::RPAREN) {
             self.next_token();
         } else {
+            self.errors.push(ParseError::IfMissingRightParen {
+                found: self.peek_token.clone(),
+                position: self.peek_pos,
+            });
             return None;
         }
 
         if matches!(self.peek_token, Token::LBRACE) {
             self.next_token();
         } else {
+            self.errors.push(ParseError::IfMissingLeftBrace {
+                found: self.peek_token.clone(),
+                position: self.peek_pos,
+            });
             return None;
         }
 
@@ -291,6 +546,10 @@ impl Parser {
             if matches!(self.peek_token, Token::LBRACE) {
                 self.next_token();
             } else {
+                self.errors.push(ParseError::IfMissingLeftBrace {
+                    found: self.peek_token.clone(),
+                    position: self.peek_pos,
+                });
                 return None;
             }
 
@@ -306,6 +565,10 @@ impl Parser {
         if matches!(self.peek_token, Token::LPAREN) {
             self.next_token();
         } else {
+            self.errors.push(ParseError::FnMissingLeftParen {
+                found: self.peek_token.clone(),
+                position: self.peek_pos,
+            });
             return None;
         }
 
@@ -314,6 +577,10 @@ impl Parser {
         if matches!(self.peek_token, Token::LBRACE) {
             self.next_token();
         } else {
+            self.errors.push(ParseError::FnMissingLeftBrace {
+                found: self.peek_token.clone(),
+                position: self.peek_pos,
+            });
             return None;
         }
 
@@ -352,6 +619,10 @@ impl Parser {
         if matches!(self.peek_token, Token::RPAREN) {
             self.next_token();
         } else {
+            self.errors.push(ParseError::FnMissingParams {
+                found: self.peek_token.clone(),
+                position: self.peek_pos,
+            });
             return None;
         }
 
@@ -374,6 +645,10 @@ impl Parser {
             if matches!(self.peek_token, Token::COLON) {
                 self.next_token();
             } else {
+                self.errors.push(ParseError::HashMissingColon {
+                    found: self.peek_token.clone(),
+                    position: self.peek_pos,
+                });
                 return None;
             }
 
@@ -385,6 +660,10 @@ impl Parser {
                 if matches!(self.peek_token, Token::COMMA) {
                     self.next_token();
                 } else {
+                    self.errors.push(ParseError::HashMissingCommaOrRightBrace {
+                        found: self.peek_token.clone(),
+                        position: self.peek_pos,
+                    });
                     return None;
                 }
             }
@@ -393,6 +672,10 @@ impl Parser {
         if matches!(self.peek_token, Token::RBRACE) {
             self.next_token();
         } else {
+            self.errors.push(ParseError::HashMissingCommaOrRightBrace {
+                found: self.peek_token.clone(),
+                position: self.peek_pos,
+            });
             return None;
         }
 
@@ -419,6 +702,18 @@ impl Parser {
         if self.peek_token == token {
             self.next_token();
         } else {
+            let error = if token == Token::RBRACKET {
+                ParseError::MissingRightBracket {
+                    found: self.peek_token.clone(),
+                    position: self.peek_pos,
+                }
+            } else {
+                ParseError::MissingRightParen {
+                    found: self.peek_token.clone(),
+                    position: self.peek_pos,
+                }
+            };
+            self.errors.push(error);
             return None;
         }
 
@@ -445,9 +740,19 @@ impl Parser {
         }))
     }
 
+    /// Builds an `InfixExpression` regardless of whether `left`/`right` are
+    /// `IntegerLiteral` or `FloatLiteral` nodes — the parser doesn't have a
+    /// numeric tower, it just records the operator and operands. Mixed
+    /// int/float operands (`1 + 2.5`) are legal at this layer; promoting
+    /// `IntegerLiteral` to `f64` when one side is a float is the
+    /// evaluator's job, not the parser's.
     fn parse_infix_expression(&mut self, left: Expression) -> Option<Expression> {
         let token = self.cur_token.clone();
-        let precedence = Self::get_precedence(&self.cur_token);
+        let precedence = self
+            .precedences
+            .get(&token.kind())
+            .copied()
+            .unwrap_or(PrecedenceType::LOWEST);
 
         self.next_token();
         let right = self.parse_expression(precedence)?;
@@ -457,27 +762,97 @@ impl Parser {
             right: Box::new(right),
         }))
     }
-
-    fn get_precedence(token: &Token) -> PrecedenceType {
-        match token {
-            Token::EQ | Token::NEQ => PrecedenceType::EQUALS,
-            Token::LT | Token::GT => PrecedenceType::LESSGREATER,
-            Token::PLUS | Token::MINUS => PrecedenceType::SUM,
-            Token::SLASH | Token::ASTERISK => PrecedenceType::PRODUCT,
-            Token::LPAREN => PrecedenceType::CALL,
-            _ => PrecedenceType::LOWEST,
-        }
-    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::lexer::Lexer;
+    use crate::lexer::{Lexer, Position};
+    use crate::parser::error::ParseError;
     use crate::parser::expression::{CallExpression, Expression, FunctionLiteral};
+    use crate::parser::program::Program;
     use crate::parser::statement::{ExpressionStatement, Statement};
-    use crate::parser::Parser;
+    use crate::parser::{Parser, PrecedenceType};
+    use crate::token::Token;
     use std::collections::HashMap;
 
+    fn parse_ok(input: &str) -> Program {
+        Parser::new(Lexer::new(input))
+            .parse()
+            .unwrap_or_else(|errors| panic!("parser errors: {:?}", errors))
+    }
+
+    #[test]
+    fn test_register_infix_adds_custom_operator() {
+        use crate::token::TokenKind;
+
+        let mut parser = Parser::new(Lexer::new("5 : 3;"));
+        parser.register_infix(
+            TokenKind::COLON,
+            PrecedenceType::PRODUCT,
+            crate::parser::Associativity::Left,
+            Parser::parse_infix_expression,
+        );
+
+        let program = parser
+            .parse()
+            .unwrap_or_else(|errors| panic!("parser errors: {:?}", errors));
+
+        let exp_stmt = unwrap_to_expression_statement(&program.statements[0]).unwrap();
+        assert_infix_expression(
+            exp_stmt.expression.as_ref().unwrap(),
+            Literal::NumberLiteral(5),
+            ":",
+            Literal::NumberLiteral(3),
+        );
+    }
+
+    #[test]
+    fn test_parse_returns_err_on_invalid_statement() {
+        let errors = Parser::new(Lexer::new("let = 5;"))
+            .parse()
+            .expect_err("missing identifier after let should fail to parse");
+
+        assert!(!errors.is_empty());
+    }
+
+    #[test]
+    fn test_parser_recovers_and_collects_multiple_errors() {
+        let input = "let = 5;\nlet y = 10;\nlet = 15;";
+
+        let mut parser = Parser::new(Lexer::new(input));
+        let program = parser.parse_program();
+
+        assert_eq!(parser.errors().len(), 2);
+        for error in parser.errors() {
+            match error {
+                ParseError::VarExpectsIdentifier { .. } => {}
+                other => panic!("expected VarExpectsIdentifier, got {:?}", other),
+            }
+        }
+
+        assert_eq!(program.statements.len(), 1);
+        if let Statement::LetStatement(s) = &program.statements[0] {
+            assert_eq!(s.identifier.token.to_string(), "y");
+        } else {
+            panic!("expected the one cleanly-parsed LetStatement to survive recovery");
+        }
+    }
+
+    #[test]
+    fn test_parse_error_reports_kind_and_position() {
+        let errors = Parser::new(Lexer::new("let x = 5;\nlet = 10;"))
+            .parse()
+            .expect_err("missing identifier after let should fail to parse");
+
+        match &errors[0] {
+            ParseError::VarExpectsIdentifier { found, position } => {
+                assert_eq!(*found, Token::ASSIGN);
+                assert_eq!(*position, Position { line: 2, column: 5 });
+            }
+            other => panic!("expected VarExpectsIdentifier, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_parser() {
         let input = "
@@ -485,19 +860,7 @@ let x = 5;
 let y = 10;
 return 5;
 ";
-        let lexer = Lexer::new(input);
-        let mut parser = Parser::new(lexer);
-
-        let program = parser.parse_program();
-        if parser.errors.len() > 0 {
-            println!(
-                "parser.errors.len() = {:?}, {:?}",
-                parser.errors.len(),
-                parser.errors
-            );
-            assert_eq!(parser.errors.len(), 0);
-            return;
-        }
+        let program = parse_ok(input);
 
         if let Statement::LetStatement(s) = &program.statements[0] {
             assert_eq!(s.token.to_string(), "let");
@@ -524,14 +887,7 @@ return 5;
     fn test_identifier_expression() {
         let input = "foobar;";
 
-        let lexer = Lexer::new(input);
-        let mut parser = Parser::new(lexer);
-        let program = parser.parse_program();
-
-        if parser.errors.len() > 0 {
-            println!("error: {:?}", parser.errors);
-            return;
-        }
+        let program = parse_ok(input);
 
         for s in &program.statements {
             println!("{}", s);
@@ -547,12 +903,7 @@ return 5;
     fn test_integer_literal_expression() {
         let input = "5;";
 
-        let mut parser = Parser::new(Lexer::new(input));
-        let program = parser.parse_program();
-        if parser.errors.len() > 0 {
-            println!("{:?}", parser.errors);
-            return;
-        }
+        let program = parse_ok(input);
 
         for s in &program.statements {
             println!("{}", s);
@@ -564,6 +915,43 @@ return 5;
         assert_integer_literal(exp_stmt.expression.as_ref().unwrap(), 5);
     }
 
+    #[test]
+    fn test_float_literal_expression() {
+        let input = "2.5;";
+
+        let program = parse_ok(input);
+        assert_eq!(program.statements.len(), 1);
+
+        let exp_stmt = unwrap_to_expression_statement(&program.statements[0]).unwrap();
+        match exp_stmt.expression.as_ref().unwrap() {
+            Expression::FloatLiteral(f) => assert_eq!(f.value, 2.5),
+            other => panic!("expected FloatLiteral, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_float_literal_edge_cases() {
+        let cases = vec![("5.;", 5.0), (".5;", 0.5), ("1e10;", 1e10), ("2.5e-3;", 2.5e-3)];
+
+        for (input, expected) in cases {
+            let program = parse_ok(input);
+            let exp_stmt = unwrap_to_expression_statement(&program.statements[0]).unwrap();
+            assert_float_literal(exp_stmt.expression.as_ref().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_malformed_float_literal_is_a_parse_error() {
+        let errors = Parser::new(Lexer::new("1.2.3;"))
+            .parse()
+            .expect_err("'1.2.3' should fail to parse");
+
+        match &errors[0] {
+            ParseError::NoPrefixParseFn { .. } => {}
+            other => panic!("expected NoPrefixParseFn, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_prefix_expression() {
         let prefix_tests = vec![
@@ -574,13 +962,7 @@ return 5;
         ];
 
         for test in prefix_tests {
-            let mut parser = Parser::new(Lexer::new(test.0));
-            let program = parser.parse_program();
-            if parser.errors.len() > 0 {
-                println!("{:?}", parser.errors);
-                assert!(false);
-                continue;
-            }
+            let program = parse_ok(test.0);
 
             for s in &program.statements {
                 println!("{}", s);
@@ -663,13 +1045,7 @@ return 5;
         ];
 
         for test in infix_tests {
-            let mut parser = Parser::new(Lexer::new(test.0));
-            let program = parser.parse_program();
-            if parser.errors.len() > 0 {
-                println!("{:?}", parser.errors);
-                assert!(false);
-                continue;
-            }
+            let program = parse_ok(test.0);
 
             for s in &program.statements {
                 println!("{}", s);
@@ -722,16 +1098,13 @@ return 5;
                 "add(a + b + c * d / f + g)",
                 "add((((a + b) + ((c * d) / f)) + g))",
             ),
+            ("2 ** 3 ** 2", "(2 ** (3 ** 2))"),
+            ("2 ** 3 * 2", "((2 ** 3) * 2)"),
+            ("-2 ** 2", "((-2) ** 2)"),
         ];
 
         for test in test_vec {
-            let mut parser = Parser::new(Lexer::new(test.0));
-            let program = parser.parse_program();
-            if parser.errors.len() > 0 {
-                println!("{:?}", parser.errors);
-                assert!(false);
-                continue;
-            }
+            let program = parse_ok(test.0);
 
             println!("{}", program);
             assert_eq!(program.to_string(), test.1);
@@ -742,13 +1115,7 @@ return 5;
     fn test_if_expression() {
         let input = "if (x < y) { x }";
 
-        let mut parser = Parser::new(Lexer::new(input));
-        let program = parser.parse_program();
-        if parser.errors.len() > 0 {
-            println!("{:?}", parser.errors);
-            assert!(false);
-            return;
-        }
+        let program = parse_ok(input);
 
         println!("{}", program);
         assert_eq!(program.statements.len(), 1);
@@ -782,13 +1149,7 @@ return 5;
     #[test]
     fn test_function_literal_parsing() {
         let input = "fn (x, y) { x + y; }";
-        let mut parser = Parser::new(Lexer::new(input));
-        let program = parser.parse_program();
-        if parser.errors.len() > 0 {
-            println!("{:?}", parser.errors);
-            assert!(false);
-            return;
-        }
+        let program = parse_ok(input);
 
         println!("{}", program);
         assert_eq!(program.statements.len(), 1);
@@ -817,13 +1178,7 @@ return 5;
     #[test]
     fn test_call_expression() {
         let input = "add(1, 2 * 3, 4 + 5);";
-        let mut parser = Parser::new(Lexer::new(input));
-        let program = parser.parse_program();
-        if parser.errors.len() > 0 {
-            println!("{:?}", parser.errors);
-            assert!(false);
-            return;
-        }
+        let program = parse_ok(input);
 
         assert_eq!(program.statements.len(), 1);
 
@@ -889,6 +1244,14 @@ return 5;
         }
     }
 
+    fn assert_float_literal(exp: &Expression, value: f64) {
+        if let Expression::FloatLiteral(r) = exp {
+            assert_eq!(r.value, value);
+        } else {
+            assert!(false);
+        }
+    }
+
     fn assert_bool_literal(exp: &Expression, value: bool) {
         if let Expression::BooleanLiteral(r) = exp {
             assert_eq!(r.value, value);
@@ -941,18 +1304,85 @@ return 5;
     #[test]
     fn test_string_literal_expression() {
         let input = "\"hello world!\"";
-        let mut parser = Parser::new(Lexer::new(input));
-        let program = parser.parse_program();
+        let program = parse_ok(input);
 
         let exp_stmt = unwrap_to_expression_statement(&program.statements[0]).unwrap();
         assert_string_literal(exp_stmt.expression.as_ref().unwrap(), "hello world!");
     }
 
+    #[test]
+    fn test_string_literal_with_escape_sequences() {
+        let input = r#""line1\nline2\t\"quoted\"""#;
+        let program = parse_ok(input);
+
+        let exp_stmt = unwrap_to_expression_statement(&program.statements[0]).unwrap();
+        assert_string_literal(exp_stmt.expression.as_ref().unwrap(), "line1\nline2\t\"quoted\"");
+    }
+
+    #[test]
+    fn test_char_literal_expression() {
+        let input = r"'a';";
+        let program = parse_ok(input);
+
+        let exp_stmt = unwrap_to_expression_statement(&program.statements[0]).unwrap();
+        match exp_stmt.expression.as_ref().unwrap() {
+            Expression::CharLiteral(c) => assert_eq!(c.value, 'a'),
+            other => panic!("expected CharLiteral, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_malformed_char_literal_is_a_parse_error() {
+        let errors = Parser::new(Lexer::new("'ab';"))
+            .parse()
+            .expect_err("over-long char literal should fail to parse");
+
+        match &errors[0] {
+            ParseError::NoPrefixParseFn { .. } => {}
+            other => panic!("expected NoPrefixParseFn, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_byte_string_literal_expression() {
+        let input = r#"b"hi";"#;
+        let program = parse_ok(input);
+
+        let exp_stmt = unwrap_to_expression_statement(&program.statements[0]).unwrap();
+        match exp_stmt.expression.as_ref().unwrap() {
+            Expression::ByteStringLiteral(b) => assert_eq!(b.value, b"hi".to_vec()),
+            other => panic!("expected ByteStringLiteral, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_string_interpolation_desugars_to_concatenation() {
+        let input = r#""hi ${name}!""#;
+        let program = parse_ok(input);
+
+        let exp_stmt = unwrap_to_expression_statement(&program.statements[0]).unwrap();
+        let outer = exp_stmt.expression.as_ref().unwrap();
+
+        if let Expression::InfixExpression(outer) = outer {
+            assert_eq!(outer.token.to_string(), "+");
+            assert_string_literal(&outer.right, "!");
+
+            if let Expression::InfixExpression(inner) = outer.left.as_ref() {
+                assert_eq!(inner.token.to_string(), "+");
+                assert_string_literal(&inner.left, "hi ");
+                assert_identifier(&inner.right, "name");
+            } else {
+                panic!("expected nested InfixExpression, got {:?}", outer.left);
+            }
+        } else {
+            panic!("expected InfixExpression, got {:?}", outer);
+        }
+    }
+
     #[test]
     fn test_array_literal_expression() {
         let input = "[1, 2*2, 3+3]";
-        let mut parser = Parser::new(Lexer::new(input));
-        let program = parser.parse_program();
+        let program = parse_ok(input);
 
         let exp_stmt = unwrap_to_expression_statement(&program.statements[0]).unwrap();
         let expression = exp_stmt.expression.as_ref().unwrap();
@@ -985,8 +1415,7 @@ return 5;
         expected.insert("three", 3);
 
         let input = r#"{"one": 1, "two": 2, "three": 3}"#;
-        let mut parser = Parser::new(Lexer::new(input));
-        let program = parser.parse_program();
+        let program = parse_ok(input);
 
         let exp_stmt = unwrap_to_expression_statement(&program.statements[0]).unwrap();
         let expression = exp_stmt.expression.as_ref().unwrap();
@@ -1014,8 +1443,7 @@ return 5;
     #[test]
     fn test_hash_literal_empty() {
         let input = "{}";
-        let mut parser = Parser::new(Lexer::new(input));
-        let program = parser.parse_program();
+        let program = parse_ok(input);
 
         let exp_stmt = unwrap_to_expression_statement(&program.statements[0]).unwrap();
         let expression = exp_stmt.expression.as_ref().unwrap();
@@ -1053,8 +1481,7 @@ return 5;
         });
 
         let input = r#"{"one": 1 + 0, "two": 10 - 8, "three": 15 / 5}"#;
-        let mut parser = Parser::new(Lexer::new(input));
-        let program = parser.parse_program();
+        let program = parse_ok(input);
 
         let exp_stmt = unwrap_to_expression_statement(&program.statements[0]).unwrap();
         let expression = exp_stmt.expression.as_ref().unwrap();
@@ -0,0 +1,6 @@
+use std::fmt;
+
+/// Implemented by every AST node so it can be rendered as text for tests.
+/// The rendering is not always valid Monkey source: blocks print without
+/// braces and strings without quotes.
+pub trait Node: fmt::Display {}
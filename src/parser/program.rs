@@ -0,0 +1,20 @@
+use std::fmt;
+
+use crate::parser::node::Node;
+use crate::parser::statement::Statement;
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Program {
+    pub statements: Vec<Statement>,
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for statement in &self.statements {
+            write!(f, "{}", statement)?;
+        }
+        Ok(())
+    }
+}
+
+impl Node for Program {}
@@ -0,0 +1,88 @@
+use std::fmt;
+
+use crate::parser::expression::{Expression, Identifier};
+use crate::parser::node::Node;
+use crate::token::Token;
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Statement {
+    LetStatement(LetStatement),
+    ReturnStatement(ReturnStatement),
+    ExpressionStatement(ExpressionStatement),
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Statement::LetStatement(s) => write!(f, "{}", s),
+            Statement::ReturnStatement(s) => write!(f, "{}", s),
+            Statement::ExpressionStatement(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl Node for Statement {}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LetStatement {
+    pub token: Token,
+    pub identifier: Identifier,
+    pub value: Option<Expression>,
+}
+
+impl fmt::Display for LetStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} = ", self.token.literal(), self.identifier)?;
+        if let Some(value) = &self.value {
+            write!(f, "{}", value)?;
+        }
+        write!(f, ";")
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ReturnStatement {
+    pub token: Token,
+    pub value: Option<Expression>,
+}
+
+impl fmt::Display for ReturnStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.token.literal())?;
+        if let Some(value) = &self.value {
+            write!(f, "{}", value)?;
+        }
+        write!(f, ";")
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ExpressionStatement {
+    pub token: Token,
+    pub expression: Option<Expression>,
+}
+
+impl fmt::Display for ExpressionStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(expression) = &self.expression {
+            write!(f, "{}", expression)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BlockStatement {
+    pub token: Token,
+    pub statements: Vec<Statement>,
+}
+
+impl fmt::Display for BlockStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for statement in &self.statements {
+            write!(f, "{}", statement)?;
+        }
+        Ok(())
+    }
+}
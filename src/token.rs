@@ -0,0 +1,206 @@
+use std::fmt;
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Token {
+    ILLEGAL(String),
+    EOF,
+
+    IDENT(String),
+    INT(String),
+    FLOAT(String),
+    STRING(String),
+    /// A string segment cut short by `${`, with the interpolated
+    /// expression's tokens following until the matching `}`.
+    INTERP_STRING_PART(String),
+    /// A single decoded Unicode scalar from a `'...'` char literal.
+    CHAR(String),
+    /// The decoded text of a `b"..."` byte-string literal, before it's
+    /// turned into a `Vec<u8>` by the parser.
+    BYTE_STRING(String),
+
+    ASSIGN,
+    PLUS,
+    MINUS,
+    BANG,
+    ASTERISK,
+    SLASH,
+    POW,
+
+    LT,
+    GT,
+    EQ,
+    NEQ,
+
+    COMMA,
+    SEMICOLON,
+    COLON,
+
+    LPAREN,
+    RPAREN,
+    LBRACE,
+    RBRACE,
+    LBRACKET,
+    RBRACKET,
+
+    FUNCTION,
+    LET,
+    TRUE,
+    FALSE,
+    IF,
+    ELSE,
+    RETURN,
+}
+
+/// The variant of a `Token` with its payload stripped, so it can key a
+/// `HashMap` without every distinct `IDENT`/`INT`/... value needing its own
+/// entry. Used by the parser's pluggable prefix/infix dispatch tables.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TokenKind {
+    ILLEGAL,
+    EOF,
+
+    IDENT,
+    INT,
+    FLOAT,
+    STRING,
+    INTERP_STRING_PART,
+    CHAR,
+    BYTE_STRING,
+
+    ASSIGN,
+    PLUS,
+    MINUS,
+    BANG,
+    ASTERISK,
+    SLASH,
+    POW,
+
+    LT,
+    GT,
+    EQ,
+    NEQ,
+
+    COMMA,
+    SEMICOLON,
+    COLON,
+
+    LPAREN,
+    RPAREN,
+    LBRACE,
+    RBRACE,
+    LBRACKET,
+    RBRACKET,
+
+    FUNCTION,
+    LET,
+    TRUE,
+    FALSE,
+    IF,
+    ELSE,
+    RETURN,
+}
+
+impl Token {
+    pub fn kind(&self) -> TokenKind {
+        match self {
+            Token::ILLEGAL(_) => TokenKind::ILLEGAL,
+            Token::EOF => TokenKind::EOF,
+            Token::IDENT(_) => TokenKind::IDENT,
+            Token::INT(_) => TokenKind::INT,
+            Token::FLOAT(_) => TokenKind::FLOAT,
+            Token::STRING(_) => TokenKind::STRING,
+            Token::INTERP_STRING_PART(_) => TokenKind::INTERP_STRING_PART,
+            Token::CHAR(_) => TokenKind::CHAR,
+            Token::BYTE_STRING(_) => TokenKind::BYTE_STRING,
+            Token::ASSIGN => TokenKind::ASSIGN,
+            Token::PLUS => TokenKind::PLUS,
+            Token::MINUS => TokenKind::MINUS,
+            Token::BANG => TokenKind::BANG,
+            Token::ASTERISK => TokenKind::ASTERISK,
+            Token::SLASH => TokenKind::SLASH,
+            Token::POW => TokenKind::POW,
+            Token::LT => TokenKind::LT,
+            Token::GT => TokenKind::GT,
+            Token::EQ => TokenKind::EQ,
+            Token::NEQ => TokenKind::NEQ,
+            Token::COMMA => TokenKind::COMMA,
+            Token::SEMICOLON => TokenKind::SEMICOLON,
+            Token::COLON => TokenKind::COLON,
+            Token::LPAREN => TokenKind::LPAREN,
+            Token::RPAREN => TokenKind::RPAREN,
+            Token::LBRACE => TokenKind::LBRACE,
+            Token::RBRACE => TokenKind::RBRACE,
+            Token::LBRACKET => TokenKind::LBRACKET,
+            Token::RBRACKET => TokenKind::RBRACKET,
+            Token::FUNCTION => TokenKind::FUNCTION,
+            Token::LET => TokenKind::LET,
+            Token::TRUE => TokenKind::TRUE,
+            Token::FALSE => TokenKind::FALSE,
+            Token::IF => TokenKind::IF,
+            Token::ELSE => TokenKind::ELSE,
+            Token::RETURN => TokenKind::RETURN,
+        }
+    }
+
+    pub fn lookup_ident(ident: &str) -> Token {
+        match ident {
+            "fn" => Token::FUNCTION,
+            "let" => Token::LET,
+            "true" => Token::TRUE,
+            "false" => Token::FALSE,
+            "if" => Token::IF,
+            "else" => Token::ELSE,
+            "return" => Token::RETURN,
+            _ => Token::IDENT(ident.to_string()),
+        }
+    }
+
+    pub fn literal(&self) -> &str {
+        match self {
+            Token::ILLEGAL(s)
+            | Token::IDENT(s)
+            | Token::INT(s)
+            | Token::FLOAT(s)
+            | Token::STRING(s)
+            | Token::INTERP_STRING_PART(s)
+            | Token::CHAR(s)
+            | Token::BYTE_STRING(s) => s,
+            Token::EOF => "",
+            Token::ASSIGN => "=",
+            Token::PLUS => "+",
+            Token::MINUS => "-",
+            Token::BANG => "!",
+            Token::ASTERISK => "*",
+            Token::SLASH => "/",
+            Token::POW => "**",
+            Token::LT => "<",
+            Token::GT => ">",
+            Token::EQ => "==",
+            Token::NEQ => "!=",
+            Token::COMMA => ",",
+            Token::SEMICOLON => ";",
+            Token::COLON => ":",
+            Token::LPAREN => "(",
+            Token::RPAREN => ")",
+            Token::LBRACE => "{",
+            Token::RBRACE => "}",
+            Token::LBRACKET => "[",
+            Token::RBRACKET => "]",
+            Token::FUNCTION => "fn",
+            Token::LET => "let",
+            Token::TRUE => "true",
+            Token::FALSE => "false",
+            Token::IF => "if",
+            Token::ELSE => "else",
+            Token::RETURN => "return",
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.literal())
+    }
+}